@@ -0,0 +1,312 @@
+use ink_primitives::KeyPtr;
+use ink_storage::traits::{SpreadAllocate, SpreadLayout, PackedLayout};
+use crate::errors::TribeError;
+
+/// Decides when a forming tribe has enough founder commitment to activate
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum ActivationPolicy {
+    /// Every founder has settled, no founder `has_pending_activity`
+    AllSettled,
+    /// Every required founder is fully funded and at least this many optional founders are fully funded
+    RequiredFundedPlusOptional(u32),
+    /// Founders who have not rejected have funded at least this amount in pico between them
+    MinimumTotalFunded(u128),
+    /// Founders who have not rejected have funded at least this percentage (1 to 100) of what they promised
+    PercentageFunded(u8),
+    /// The tribe raises toward one shared goal: any accepted founder may contribute beyond `amount_promised`,
+    /// the tribe activates once `soft_cap` is raised and anything above `hard_cap` is scaled back pro rata
+    FundingTarget { soft_cap: u128, hard_cap: u128 },
+}
+
+impl ActivationPolicy {
+
+    pub fn validate(&self) -> Result<(), TribeError> {
+        match self {
+            ActivationPolicy::MinimumTotalFunded(0) => Err(TribeError::InvalidActivationPolicy),
+            ActivationPolicy::PercentageFunded(percent) if *percent == 0 || *percent > 100 => Err(TribeError::InvalidActivationPolicy),
+            ActivationPolicy::FundingTarget { soft_cap, hard_cap } if *soft_cap == 0 || soft_cap > hard_cap => Err(TribeError::InvalidFundingTarget),
+            _ => Ok(())
+        }
+    }
+
+    /// The shared `hard_cap` when the tribe raises toward a `FundingTarget`
+    pub fn hard_cap(&self) -> Option<u128> {
+        match self {
+            ActivationPolicy::FundingTarget { hard_cap, .. } => Some(*hard_cap),
+            _ => None
+        }
+    }
+}
+
+/// What happens to the partial funds of an optional founder ejected for not funding
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum LaggardFunds {
+    /// The ejected founder can `claim_refund` like any founder who rejected
+    Refunded,
+    /// The funds stay with the tribe
+    Forfeited,
+}
+
+/// Whether a founder who has funded may still reject the tribe
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum CommitmentRule {
+    /// Founders may reject at any time while the tribe is forming
+    Unrestricted,
+    /// Founders can not reject once they have funded
+    Forbidden,
+    /// Founders who have funded can only reject by giving up this percentage (1 to 100) of their funds to the other funded founders
+    Penalty(u8),
+    /// Founders who have funded can only reject up to and including this block
+    CoolingOff(u32),
+}
+
+impl CommitmentRule {
+
+    pub fn validate(&self) -> Result<(), TribeError> {
+        match self {
+            CommitmentRule::Penalty(percent) if *percent == 0 || *percent > 100 => Err(TribeError::InvalidCommitmentRule),
+            _ => Ok(())
+        }
+    }
+
+    /// Checks a plain rejection by a founder who has funded at `block_number`
+    pub fn check_rejection(&self, block_number: u32) -> Result<(), TribeError> {
+        match self {
+            CommitmentRule::Unrestricted => Ok(()),
+            CommitmentRule::Forbidden => Err(TribeError::RejectionAfterFundingForbidden),
+            CommitmentRule::Penalty(_) => Err(TribeError::RejectionRequiresPenalty),
+            CommitmentRule::CoolingOff(last_block) if block_number > *last_block => Err(TribeError::CoolingOffPeriodEnded),
+            CommitmentRule::CoolingOff(_) => Ok(())
+        }
+    }
+}
+
+/// How much say each accepted founder has in governance proposals
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum VoteWeighting {
+    /// Votes are weighted by the founder's share balance, minted from `amount_funded` when the tribe activates
+    ByShares,
+    /// Every founder has one vote
+    OnePerFounder,
+}
+
+/// Thresholds applied to governance proposals of an active tribe
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct GovernanceConfig {
+    pub weighting: VoteWeighting,
+    /// Percentage (1 to 100) of the total voting weight that has to vote for a proposal to be decided
+    pub quorum: u8,
+    /// A proposal passes when more than this percentage (0 to 99) of the weight cast approves
+    pub approval_threshold: u8,
+    /// Blocks a proposal stays open for voting
+    pub voting_period: u32,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            weighting: VoteWeighting::ByShares,
+            quorum: 50,
+            approval_threshold: 50,
+            voting_period: 100,
+        }
+    }
+}
+
+impl GovernanceConfig {
+
+    pub fn validate(&self) -> Result<(), TribeError> {
+        if self.quorum == 0 || self.quorum > 100 || self.approval_threshold >= 100 || self.voting_period == 0 {
+            return Err(TribeError::InvalidGovernanceConfig);
+        }
+        Ok(())
+    }
+}
+
+/// Rules chosen when the tribe is created
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct TribeConfig {
+    pub activation_policy: ActivationPolicy,
+    /// Blocks after accepting before the others can vote to eject a founder who is lagging, `None` disables ejection
+    pub funding_grace_period: Option<u32>,
+    pub laggard_funds: LaggardFunds,
+    /// Freeze the roster as soon as a founder other than the initial founder funds the tribe
+    pub freeze_roster_on_funding: bool,
+    pub commitment_rule: CommitmentRule,
+    pub governance: GovernanceConfig,
+}
+
+impl Default for TribeConfig {
+    fn default() -> Self {
+        Self {
+            activation_policy: ActivationPolicy::AllSettled,
+            funding_grace_period: None,
+            laggard_funds: LaggardFunds::Refunded,
+            freeze_roster_on_funding: true,
+            commitment_rule: CommitmentRule::Unrestricted,
+            governance: GovernanceConfig::default(),
+        }
+    }
+}
+
+impl SpreadAllocate for TribeConfig {
+    fn allocate_spread(ptr: &mut KeyPtr) -> Self {
+        ptr.advance_by(<Self as SpreadLayout>::FOOTPRINT);
+        TribeConfig::default()
+    }
+}
+
+impl TribeConfig {
+
+    pub fn validate(&self) -> Result<(), TribeError> {
+        self.activation_policy.validate()?;
+        self.commitment_rule.validate()?;
+        self.governance.validate()
+    }
+}
+
+///
+/// Config Unit Tests
+///
+#[cfg(test)]
+mod config_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    //policy, expected
+    macro_rules! activation_policy_validate {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (policy, expected): (ActivationPolicy, Result<(), TribeError>) = $value;
+                let config = TribeConfig { activation_policy: policy, ..TribeConfig::default() };
+
+                //ACT
+                let result = config.validate();
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    activation_policy_validate! {
+        validate_all_settled: (ActivationPolicy::AllSettled, Ok(())),
+        validate_required_funded_plus_no_optional: (ActivationPolicy::RequiredFundedPlusOptional(0), Ok(())),
+        validate_required_funded_plus_optional: (ActivationPolicy::RequiredFundedPlusOptional(3), Ok(())),
+        validate_minimum_total_funded: (ActivationPolicy::MinimumTotalFunded(5000), Ok(())),
+        validate_minimum_total_funded_zero: (ActivationPolicy::MinimumTotalFunded(0), Err(TribeError::InvalidActivationPolicy)),
+        validate_percentage_funded: (ActivationPolicy::PercentageFunded(60), Ok(())),
+        validate_percentage_funded_full: (ActivationPolicy::PercentageFunded(100), Ok(())),
+        validate_percentage_funded_zero: (ActivationPolicy::PercentageFunded(0), Err(TribeError::InvalidActivationPolicy)),
+        validate_percentage_funded_over_full: (ActivationPolicy::PercentageFunded(101), Err(TribeError::InvalidActivationPolicy)),
+        validate_funding_target: (ActivationPolicy::FundingTarget { soft_cap: 5000, hard_cap: 10000 }, Ok(())),
+        validate_funding_target_equal_caps: (ActivationPolicy::FundingTarget { soft_cap: 10000, hard_cap: 10000 }, Ok(())),
+        validate_funding_target_zero_soft_cap: (ActivationPolicy::FundingTarget { soft_cap: 0, hard_cap: 10000 }, Err(TribeError::InvalidFundingTarget)),
+        validate_funding_target_soft_above_hard: (ActivationPolicy::FundingTarget { soft_cap: 12000, hard_cap: 10000 }, Err(TribeError::InvalidFundingTarget)),
+    }
+
+    //rule, block_number, expected
+    macro_rules! commitment_rule_check_rejection {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (rule, block_number, expected): (CommitmentRule, u32, Result<(), TribeError>) = $value;
+
+                //ACT
+                let result = rule.check_rejection(block_number);
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    commitment_rule_check_rejection! {
+        check_rejection_unrestricted: (CommitmentRule::Unrestricted, 50, Ok(())),
+        check_rejection_forbidden: (CommitmentRule::Forbidden, 0, Err(TribeError::RejectionAfterFundingForbidden)),
+        check_rejection_penalty: (CommitmentRule::Penalty(10), 0, Err(TribeError::RejectionRequiresPenalty)),
+        check_rejection_cooling_off_open: (CommitmentRule::CoolingOff(5), 5, Ok(())),
+        check_rejection_cooling_off_ended: (CommitmentRule::CoolingOff(5), 6, Err(TribeError::CoolingOffPeriodEnded)),
+    }
+
+    //rule, expected
+    macro_rules! commitment_rule_validate {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (rule, expected): (CommitmentRule, Result<(), TribeError>) = $value;
+                let config = TribeConfig { commitment_rule: rule, ..TribeConfig::default() };
+
+                //ACT
+                let result = config.validate();
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    commitment_rule_validate! {
+        validate_penalty: (CommitmentRule::Penalty(25), Ok(())),
+        validate_penalty_full: (CommitmentRule::Penalty(100), Ok(())),
+        validate_penalty_zero: (CommitmentRule::Penalty(0), Err(TribeError::InvalidCommitmentRule)),
+        validate_penalty_over_full: (CommitmentRule::Penalty(101), Err(TribeError::InvalidCommitmentRule)),
+        validate_cooling_off: (CommitmentRule::CoolingOff(0), Ok(())),
+    }
+
+    //quorum, approval_threshold, voting_period, expected
+    macro_rules! governance_config_validate {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (quorum, approval_threshold, voting_period, expected): (u8, u8, u32, Result<(), TribeError>) = $value;
+                let governance = GovernanceConfig { quorum, approval_threshold, voting_period, ..GovernanceConfig::default() };
+                let config = TribeConfig { governance, ..TribeConfig::default() };
+
+                //ACT
+                let result = config.validate();
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    governance_config_validate! {
+        validate_governance: (50, 50, 100, Ok(())),
+        validate_governance_full_quorum: (100, 0, 1, Ok(())),
+        validate_governance_zero_quorum: (0, 50, 100, Err(TribeError::InvalidGovernanceConfig)),
+        validate_governance_quorum_over_full: (101, 50, 100, Err(TribeError::InvalidGovernanceConfig)),
+        validate_governance_full_approval: (50, 100, 100, Err(TribeError::InvalidGovernanceConfig)),
+        validate_governance_zero_voting_period: (50, 50, 0, Err(TribeError::InvalidGovernanceConfig)),
+    }
+
+    #[ink::test]
+    fn default_config_should_wait_for_every_founder() {
+        //ACT
+        let config = TribeConfig::default();
+
+        //ASSERT
+        assert_eq!(config.activation_policy, ActivationPolicy::AllSettled);
+        assert_eq!(config.funding_grace_period, None);
+        assert_eq!(config.laggard_funds, LaggardFunds::Refunded);
+        assert!(config.freeze_roster_on_funding);
+        assert_eq!(config.commitment_rule, CommitmentRule::Unrestricted);
+        assert_eq!(config.governance.weighting, VoteWeighting::ByShares);
+    }
+}
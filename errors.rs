@@ -4,18 +4,77 @@ use ink_prelude::{string::{String, ToString}};
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum TribeError {
     ActiveTribeCannotAcceptFounderAction,
+    AlreadyInitialFounder,
     AmountPromisedIsZero,
+    ArithmeticOverflow,
     CanNotInviteInitialFounder,
+    CanNotProposeOwnAmount,
+    CanNotReplaceRequiredFounder,
+    CanNotRevokeInitialFounder,
+    CanNotVoteToEjectSelf,
+    CoolingOffPeriodEnded,
+    EjectionDisabled,
+    EjectionVoteAlreadyCast,
     FounderAlreadyInvited,
+    FounderAlreadyReplaced,
+    FounderHasFunds,
     FounderListNotFound,
+    FounderNotLagging,
+    FounderNotPending,
+    FounderNotRejected,
     FounderRejectedInvitation,
     FounderVoteActionPending,
+    FoundingDeadlineNotReached,
+    FoundingDeadlinePassed,
     FundingAlreadyCompleted,
     FundingAmountMustBeGreaterThanZero,
+    InstallmentPlanIsFixed,
+    InsufficientTreasuryFunds,
+    InvalidActivationPolicy,
+    InvalidCommitmentRule,
+    InvalidFoundingDeadline,
+    InvalidFundingTarget,
+    InvalidGovernanceConfig,
+    InvalidInstallmentPlan,
+    InvalidStatusTransition,
+    MemberAlreadyApproved,
+    MembershipAlreadyRequested,
+    MembershipVoteAlreadyCast,
+    MissingRole,
+    NoAmendmentPending,
+    NoFundsToRefund,
+    NoInitialFounderHandover,
+    NoInstallmentOverdue,
+    NoRejectionPenalty,
+    NoSeatTransferPending,
+    NoVotingWeight,
     NotAFounder,
+    NotAMember,
+    NotAllowedToRemoveMember,
     NotInitialFounder,
+    ProposalNotFound,
+    ProposalNotOpen,
+    ProposalVoteAlreadyCast,
+    RefundNotAvailable,
+    RefundsOutstanding,
+    RejectionAfterFundingForbidden,
+    RejectionRequiresPenalty,
+    RoleAlreadyGranted,
+    RoleNotGranted,
+    SeatSharesLocked,
+    SpendAmountIsZero,
+    SpendProposalNotFound,
+    SpendProposalNotOpen,
+    SpendVoteAlreadyCast,
+    TransferFailed,
     TribeIsDefunct,
-    TribeIsLocked
+    TribeIsDissolved,
+    TribeIsLocked,
+    TribeIsNotActive,
+    VotingPeriodEnded,
+    VotingPeriodNotEnded,
+    WithdrawalAmountMustBeGreaterThanZero,
+    WithdrawalExceedsAmountFunded
 }
 pub trait MyDisplay {
     fn fmt(&self) -> String;
@@ -25,18 +84,77 @@ impl MyDisplay for TribeError {
     fn fmt(&self) -> String {
         match self {
             TribeError::ActiveTribeCannotAcceptFounderAction => "Active tribe cannot accept founder action".to_string(),
+            TribeError::AlreadyInitialFounder => "Founder is already the initial founder".to_string(),
             TribeError::AmountPromisedIsZero => "Amount promised in pico must be greater than 0".to_string(),
+            TribeError::ArithmeticOverflow => "Arithmetic overflow while calculating an amount".to_string(),
             TribeError::CanNotInviteInitialFounder => "The initial founder can not be invited to join their own tribe".to_string(),
+            TribeError::CanNotProposeOwnAmount => "A founder can not propose a new amount promised for themselves".to_string(),
+            TribeError::CanNotReplaceRequiredFounder => "A required founder can not be replaced".to_string(),
+            TribeError::CanNotRevokeInitialFounder => "The initial founder can not be revoked from their own tribe".to_string(),
+            TribeError::CanNotVoteToEjectSelf => "Founder can not vote to eject themselves".to_string(),
+            TribeError::CoolingOffPeriodEnded => "Cooling-off period has ended, founders who have funded can no longer reject the tribe".to_string(),
+            TribeError::EjectionDisabled => "Tribe was created without a funding grace period".to_string(),
+            TribeError::EjectionVoteAlreadyCast => "Founder already voted to eject this founder".to_string(),
             TribeError::FounderAlreadyInvited => "AccountId already exists as a Founder".to_string(),
+            TribeError::FounderAlreadyReplaced => "Founder has already been replaced".to_string(),
+            TribeError::FounderHasFunds => "Founder has already funded the tribe".to_string(),
             TribeError::FounderListNotFound => "Tribe list of founders  not found".to_string(),
+            TribeError::FounderNotLagging => "Founder has funded or is still within the funding grace period".to_string(),
+            TribeError::FounderNotPending => "Founder has already answered the invitation".to_string(),
+            TribeError::FounderNotRejected => "Founder has not rejected the tribe".to_string(),
             TribeError::FounderRejectedInvitation => "Founder already rejected invitation to tribe".to_string(),
             TribeError::FounderVoteActionPending => "Founder has not taken an action on pending invitation".to_string(),
+            TribeError::FoundingDeadlineNotReached => "Tribe founding deadline has not been reached".to_string(),
+            TribeError::FoundingDeadlinePassed => "Tribe founding deadline has passed".to_string(),
             TribeError::FundingAlreadyCompleted => "Founder has already completed funding".to_string(),
             TribeError::FundingAmountMustBeGreaterThanZero => "Funding amount must be greater than zero amount".to_string(),
+            TribeError::InstallmentPlanIsFixed => "Amount promised is fixed by the founder's installment plan".to_string(),
+            TribeError::InsufficientTreasuryFunds => "Treasury does not hold enough funds for this spend".to_string(),
+            TribeError::InvalidActivationPolicy => "Activation policy threshold is out of range".to_string(),
+            TribeError::InvalidCommitmentRule => "Commitment rule penalty must be between 1 and 100 percent".to_string(),
+            TribeError::InvalidFoundingDeadline => "Founding deadline must be after the current block".to_string(),
+            TribeError::InvalidFundingTarget => "Funding target soft cap must be greater than 0 and no more than the hard cap".to_string(),
+            TribeError::InvalidGovernanceConfig => "Governance quorum must be between 1 and 100 percent, approval below 100 percent and the voting period at least 1 block".to_string(),
+            TribeError::InvalidInstallmentPlan => "Installment plan needs installments greater than 0 due in increasing blocks before the founding deadline".to_string(),
+            TribeError::InvalidStatusTransition => "Tribe can not move to the requested status".to_string(),
+            TribeError::MemberAlreadyApproved => "Account is already a member of the tribe".to_string(),
+            TribeError::MembershipAlreadyRequested => "Account has already requested membership".to_string(),
+            TribeError::MembershipVoteAlreadyCast => "Founder already approved this membership request".to_string(),
+            TribeError::MissingRole => "Caller does not hold the role required for this action".to_string(),
+            TribeError::NoAmendmentPending => "Founder has no pending amendment to the amount promised".to_string(),
+            TribeError::NoFundsToRefund => "Founder has no funds to refund".to_string(),
+            TribeError::NoInitialFounderHandover => "No initial founder handover is pending for this account".to_string(),
+            TribeError::NoInstallmentOverdue => "Founder has no overdue installment".to_string(),
+            TribeError::NoRejectionPenalty => "Tribe has no rejection penalty".to_string(),
+            TribeError::NoSeatTransferPending => "No founder seat transfer is pending for this account".to_string(),
+            TribeError::NoVotingWeight => "Founder has no voting weight".to_string(),
             TribeError::NotAFounder => "AccountId is not a Founder".to_string(),
+            TribeError::NotAMember => "AccountId is not a member and has no pending membership request".to_string(),
+            TribeError::NotAllowedToRemoveMember => "Only the initial founder or the member themselves can remove a member".to_string(),
             TribeError::NotInitialFounder => "AccountId is not the Initial Founder".to_string(),
+            TribeError::ProposalNotFound => "Proposal not found".to_string(),
+            TribeError::ProposalNotOpen => "Proposal has already been closed".to_string(),
+            TribeError::ProposalVoteAlreadyCast => "Founder already voted on this proposal".to_string(),
+            TribeError::RefundNotAvailable => "Refunds are only available to rejected founders or founders of a defunct tribe".to_string(),
+            TribeError::RefundsOutstanding => "Tribe still holds funds that have not been refunded".to_string(),
+            TribeError::RejectionAfterFundingForbidden => "Founders can not reject the tribe once they have funded".to_string(),
+            TribeError::RejectionRequiresPenalty => "Founders who have funded can only reject the tribe by paying the rejection penalty".to_string(),
+            TribeError::RoleAlreadyGranted => "Account already holds this role".to_string(),
+            TribeError::RoleNotGranted => "Account does not hold this role".to_string(),
+            TribeError::SeatSharesLocked => "Seat can not be transferred while its shares back an open vote".to_string(),
+            TribeError::SpendAmountIsZero => "Spend amount must be greater than zero".to_string(),
+            TribeError::SpendProposalNotFound => "Spend proposal not found".to_string(),
+            TribeError::SpendProposalNotOpen => "Spend proposal is no longer open for voting".to_string(),
+            TribeError::SpendVoteAlreadyCast => "Founder already voted on this spend proposal".to_string(),
+            TribeError::TransferFailed => "Transfer of funds back to founder failed".to_string(),
             TribeError::TribeIsDefunct => "Tribe is defunct and cannot accept any more activity".to_string(),
-            TribeError::TribeIsLocked => "Tribe is locked due to founder activity".to_string()
+            TribeError::TribeIsDissolved => "Tribe is dissolved and cannot accept any more activity".to_string(),
+            TribeError::TribeIsLocked => "Tribe roster is frozen and can not accept new founders".to_string(),
+            TribeError::TribeIsNotActive => "Tribe is not active yet".to_string(),
+            TribeError::VotingPeriodEnded => "Voting period of this proposal has ended".to_string(),
+            TribeError::VotingPeriodNotEnded => "Voting period of this proposal has not ended yet".to_string(),
+            TribeError::WithdrawalAmountMustBeGreaterThanZero => "Withdrawal amount must be greater than zero amount".to_string(),
+            TribeError::WithdrawalExceedsAmountFunded => "Withdrawal amount exceeds the amount funded by founder".to_string()
         }
     }
 }
@@ -76,17 +194,76 @@ mod founder_tests {
         }
     }
     error_description_tests! {
+        test_already_initial_founder: (TribeError::AlreadyInitialFounder, "Founder is already the initial founder"),
         test_amount_promised_is_zero: (TribeError::AmountPromisedIsZero, "Amount promised in pico must be greater than 0"),
+        test_arithmetic_overflow: (TribeError::ArithmeticOverflow, "Arithmetic overflow while calculating an amount"),
         test_can_not_invite_initial_founder: (TribeError::CanNotInviteInitialFounder, "The initial founder can not be invited to join their own tribe"),
+        test_can_not_propose_own_amount: (TribeError::CanNotProposeOwnAmount, "A founder can not propose a new amount promised for themselves"),
+        test_can_not_replace_required_founder: (TribeError::CanNotReplaceRequiredFounder, "A required founder can not be replaced"),
+        test_can_not_revoke_initial_founder: (TribeError::CanNotRevokeInitialFounder, "The initial founder can not be revoked from their own tribe"),
+        test_can_not_vote_to_eject_self: (TribeError::CanNotVoteToEjectSelf, "Founder can not vote to eject themselves"),
+        test_cooling_off_period_ended: (TribeError::CoolingOffPeriodEnded, "Cooling-off period has ended, founders who have funded can no longer reject the tribe"),
+        test_ejection_disabled: (TribeError::EjectionDisabled, "Tribe was created without a funding grace period"),
+        test_ejection_vote_already_cast: (TribeError::EjectionVoteAlreadyCast, "Founder already voted to eject this founder"),
         test_founder_already_invited: (TribeError::FounderAlreadyInvited, "AccountId already exists as a Founder"),
+        test_founder_already_replaced: (TribeError::FounderAlreadyReplaced, "Founder has already been replaced"),
+        test_founder_has_funds: (TribeError::FounderHasFunds, "Founder has already funded the tribe"),
         test_founder_list_not_found: (TribeError::FounderListNotFound, "Tribe list of founders  not found"),
+        test_founder_not_lagging: (TribeError::FounderNotLagging, "Founder has funded or is still within the funding grace period"),
+        test_founder_not_pending: (TribeError::FounderNotPending, "Founder has already answered the invitation"),
+        test_founder_not_rejected: (TribeError::FounderNotRejected, "Founder has not rejected the tribe"),
         test_founder_rejected_invitation: (TribeError::FounderRejectedInvitation, "Founder already rejected invitation to tribe"),
         test_founder_vote_action_pending: (TribeError::FounderVoteActionPending, "Founder has not taken an action on pending invitation"),
+        test_founding_deadline_not_reached: (TribeError::FoundingDeadlineNotReached, "Tribe founding deadline has not been reached"),
+        test_founding_deadline_passed: (TribeError::FoundingDeadlinePassed, "Tribe founding deadline has passed"),
         test_funding_already_completed: (TribeError::FundingAlreadyCompleted, "Founder has already completed funding"),
         test_funding_amount_must_be_greater_than_zero: (TribeError::FundingAmountMustBeGreaterThanZero, "Funding amount must be greater than zero amount"),
+        test_installment_plan_is_fixed: (TribeError::InstallmentPlanIsFixed, "Amount promised is fixed by the founder's installment plan"),
+        test_insufficient_treasury_funds: (TribeError::InsufficientTreasuryFunds, "Treasury does not hold enough funds for this spend"),
+        test_invalid_activation_policy: (TribeError::InvalidActivationPolicy, "Activation policy threshold is out of range"),
+        test_invalid_commitment_rule: (TribeError::InvalidCommitmentRule, "Commitment rule penalty must be between 1 and 100 percent"),
+        test_invalid_founding_deadline: (TribeError::InvalidFoundingDeadline, "Founding deadline must be after the current block"),
+        test_invalid_funding_target: (TribeError::InvalidFundingTarget, "Funding target soft cap must be greater than 0 and no more than the hard cap"),
+        test_invalid_governance_config: (TribeError::InvalidGovernanceConfig, "Governance quorum must be between 1 and 100 percent, approval below 100 percent and the voting period at least 1 block"),
+        test_invalid_installment_plan: (TribeError::InvalidInstallmentPlan, "Installment plan needs installments greater than 0 due in increasing blocks before the founding deadline"),
+        test_invalid_status_transition: (TribeError::InvalidStatusTransition, "Tribe can not move to the requested status"),
+        test_member_already_approved: (TribeError::MemberAlreadyApproved, "Account is already a member of the tribe"),
+        test_membership_already_requested: (TribeError::MembershipAlreadyRequested, "Account has already requested membership"),
+        test_membership_vote_already_cast: (TribeError::MembershipVoteAlreadyCast, "Founder already approved this membership request"),
+        test_missing_role: (TribeError::MissingRole, "Caller does not hold the role required for this action"),
+        test_no_amendment_pending: (TribeError::NoAmendmentPending, "Founder has no pending amendment to the amount promised"),
+        test_no_funds_to_refund: (TribeError::NoFundsToRefund, "Founder has no funds to refund"),
+        test_no_initial_founder_handover: (TribeError::NoInitialFounderHandover, "No initial founder handover is pending for this account"),
+        test_no_installment_overdue: (TribeError::NoInstallmentOverdue, "Founder has no overdue installment"),
+        test_no_rejection_penalty: (TribeError::NoRejectionPenalty, "Tribe has no rejection penalty"),
+        test_no_seat_transfer_pending: (TribeError::NoSeatTransferPending, "No founder seat transfer is pending for this account"),
+        test_no_voting_weight: (TribeError::NoVotingWeight, "Founder has no voting weight"),
         test_not_a_founder: (TribeError::NotAFounder, "AccountId is not a Founder"),
+        test_not_a_member: (TribeError::NotAMember, "AccountId is not a member and has no pending membership request"),
+        test_not_allowed_to_remove_member: (TribeError::NotAllowedToRemoveMember, "Only the initial founder or the member themselves can remove a member"),
         test_not_initial_founder: (TribeError::NotInitialFounder, "AccountId is not the Initial Founder"),
+        test_proposal_not_found: (TribeError::ProposalNotFound, "Proposal not found"),
+        test_proposal_not_open: (TribeError::ProposalNotOpen, "Proposal has already been closed"),
+        test_proposal_vote_already_cast: (TribeError::ProposalVoteAlreadyCast, "Founder already voted on this proposal"),
+        test_refund_not_available: (TribeError::RefundNotAvailable, "Refunds are only available to rejected founders or founders of a defunct tribe"),
+        test_refunds_outstanding: (TribeError::RefundsOutstanding, "Tribe still holds funds that have not been refunded"),
+        test_rejection_after_funding_forbidden: (TribeError::RejectionAfterFundingForbidden, "Founders can not reject the tribe once they have funded"),
+        test_rejection_requires_penalty: (TribeError::RejectionRequiresPenalty, "Founders who have funded can only reject the tribe by paying the rejection penalty"),
+        test_role_already_granted: (TribeError::RoleAlreadyGranted, "Account already holds this role"),
+        test_role_not_granted: (TribeError::RoleNotGranted, "Account does not hold this role"),
+        test_seat_shares_locked: (TribeError::SeatSharesLocked, "Seat can not be transferred while its shares back an open vote"),
+        test_spend_amount_is_zero: (TribeError::SpendAmountIsZero, "Spend amount must be greater than zero"),
+        test_spend_proposal_not_found: (TribeError::SpendProposalNotFound, "Spend proposal not found"),
+        test_spend_proposal_not_open: (TribeError::SpendProposalNotOpen, "Spend proposal is no longer open for voting"),
+        test_spend_vote_already_cast: (TribeError::SpendVoteAlreadyCast, "Founder already voted on this spend proposal"),
+        test_transfer_failed: (TribeError::TransferFailed, "Transfer of funds back to founder failed"),
         test_tribe_is_defunct: (TribeError::TribeIsDefunct, "Tribe is defunct and cannot accept any more activity"),
-        test_tribe_is_locked: (TribeError::TribeIsLocked, "Tribe is locked due to founder activity"),
+        test_tribe_is_dissolved: (TribeError::TribeIsDissolved, "Tribe is dissolved and cannot accept any more activity"),
+        test_tribe_is_locked: (TribeError::TribeIsLocked, "Tribe roster is frozen and can not accept new founders"),
+        test_tribe_is_not_active: (TribeError::TribeIsNotActive, "Tribe is not active yet"),
+        test_voting_period_ended: (TribeError::VotingPeriodEnded, "Voting period of this proposal has ended"),
+        test_voting_period_not_ended: (TribeError::VotingPeriodNotEnded, "Voting period of this proposal has not ended yet"),
+        test_withdrawal_amount_must_be_greater_than_zero: (TribeError::WithdrawalAmountMustBeGreaterThanZero, "Withdrawal amount must be greater than zero amount"),
+        test_withdrawal_exceeds_amount_funded: (TribeError::WithdrawalExceedsAmountFunded, "Withdrawal amount exceeds the amount funded by founder"),
     }
 }
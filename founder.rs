@@ -1,21 +1,37 @@
 use ink_env::AccountId;
 use ink_storage::traits::{SpreadLayout, PackedLayout};
-use ink_prelude::{string::String};
+use ink_prelude::{string::String, vec::Vec};
 use crate::
 {
     errors::TribeError,
-    tribe::{FOUNDER_ACCEPTED, FOUNDER_REJECTED, FOUNDER_PENDING}
+    info::FounderInfo,
+    math::mul_div,
+    status::VoteAction
 };
 
+/// One scheduled payment of a founder's `amount_promised`, due by block `due_block`
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct Installment {
+    pub amount: u128,
+    pub due_block: u32,
+}
+
 #[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
 pub struct Founder {
     pub id: AccountId,
     pub initial: bool,
     pub required: bool,
-    pub vote_action: i32,
+    vote_action: VoteAction,
     pub amount_promised: u128,
     amount_funded: u128,
+    amount_proposed: u128,
+    amount_refundable: u128,
+    installments: Vec<Installment>,
+    accepted_block: Option<u32>,
+    amount_forfeited: u128,
+    seat_transfer_to: Option<AccountId>,
 }
 
 impl Founder {
@@ -26,22 +42,50 @@ impl Founder {
                 id,
                 initial: false,
                 required,
-                vote_action: FOUNDER_PENDING,
+                vote_action: VoteAction::Pending,
                 amount_promised,
-                amount_funded: 0
+                amount_funded: 0,
+                amount_proposed: 0,
+                amount_refundable: 0,
+                installments: Vec::new(),
+                accepted_block: None,
+                amount_forfeited: 0,
+                seat_transfer_to: None
             })
         } else {
             Err(TribeError::AmountPromisedIsZero)
         }
     }
 
+    /// Founder whose `amount_promised` is the sum of `installments`, which must be greater than 0 and due in increasing blocks
+    pub fn with_installments(id: AccountId, required: bool, installments: Vec<Installment>) -> Result<Self, TribeError> {
+        if installments.is_empty() {
+            return Err(TribeError::InvalidInstallmentPlan);
+        }
+
+        let mut amount_promised: u128 = 0;
+        let mut previous_due_block = None;
+        for installment in installments.iter() {
+            if installment.amount == 0 || previous_due_block.is_some_and(|due_block| installment.due_block <= due_block) {
+                return Err(TribeError::InvalidInstallmentPlan);
+            }
+            amount_promised = amount_promised.checked_add(installment.amount).ok_or(TribeError::InvalidInstallmentPlan)?;
+            previous_due_block = Some(installment.due_block);
+        }
+
+        let mut founder = Founder::new(id, required, amount_promised)?;
+        founder.installments = installments;
+        Ok(founder)
+    }
+
     pub fn initial_founder(id: AccountId, amount_promised: u128) -> Result<Self, TribeError> {
         let mut founder = Founder::new(id, true, amount_promised)?;
         founder.initial = true;
         Ok(founder)
     }
 
-    pub fn fund(&mut self, amount: u128) -> Result<u128, TribeError> {
+    /// Accepts funding up to the outstanding `amount_promised`. Returns the total funded and the excess that must be sent back
+    pub fn fund(&mut self, amount: u128) -> Result<(u128, u128), TribeError> {
         if amount == 0 {
             return Err(TribeError::FundingAmountMustBeGreaterThanZero);
         }
@@ -54,19 +98,235 @@ impl Founder {
         if self.is_funded() {
             return Err(TribeError::FundingAlreadyCompleted);
         }
-        else {
-            self.amount_funded += amount;
+
+        let outstanding = self.amount_promised - self.amount_funded;
+        let accepted = if amount > outstanding { outstanding } else { amount };
+        self.amount_funded += accepted;
+
+        Ok((self.amount_funded, amount - accepted))
+    }
+
+    /// Accepts funding without capping it at `amount_promised`, used when the tribe raises toward a shared target. Returns the total funded
+    pub fn contribute(&mut self, amount: u128) -> Result<u128, TribeError> {
+        if amount == 0 {
+            return Err(TribeError::FundingAmountMustBeGreaterThanZero);
+        }
+        if self.is_rejected() {
+            return Err(TribeError::FounderRejectedInvitation);
+        }
+        if !self.is_accepted() {
+            return Err(TribeError::FounderVoteActionPending)
         }
+
+        self.amount_funded += amount;
         Ok(self.amount_funded)
     }
 
+    /// Keeps the `hard_cap / raised` share of the funding and moves the rest to `amount_refundable`. Returns the amount moved
+    pub fn scale_back(&mut self, hard_cap: u128, raised: u128) -> Result<u128, TribeError> {
+        if raised <= hard_cap {
+            return Ok(0);
+        }
+
+        let kept = mul_div(self.amount_funded, hard_cap, raised).ok_or(TribeError::ArithmeticOverflow)?;
+        let excess = self.amount_funded - kept;
+        self.amount_funded = kept;
+        self.amount_refundable += excess;
+        Ok(excess)
+    }
+
+    pub fn amount_refundable(&self) -> u128 {
+        self.amount_refundable
+    }
+
+    /// Adds `amount` to what the founder can `claim_refund` at any time
+    pub fn credit_refundable(&mut self, amount: u128) {
+        self.amount_refundable += amount;
+    }
+
+    /// Zeroes `amount_refundable` and returns it so it can be sent back to the founder
+    pub fn release_refundable(&mut self) -> u128 {
+        let amount = self.amount_refundable;
+        self.amount_refundable = 0;
+        amount
+    }
+
+    pub fn vote_action(&self) -> VoteAction {
+        self.vote_action
+    }
+
+    /// Moves a pending founder to `VoteAction::Accepted` at `block_number`. Accepting again has no effect, a rejection is final
+    pub fn accept(&mut self, block_number: u32) -> Result<(), TribeError> {
+        if self.is_rejected() {
+            return Err(TribeError::FounderRejectedInvitation);
+        }
+        if self.accepted_block.is_none() {
+            self.accepted_block = Some(block_number);
+        }
+        self.vote_action = VoteAction::Accepted;
+        Ok(())
+    }
+
+    pub fn accepted_block(&self) -> Option<u32> {
+        self.accepted_block
+    }
+
+    /// An accepted founder more than `grace_period` blocks after accepting who has an overdue installment,
+    /// or who has not fully funded when they are not paying by installments
+    pub fn is_lagging(&self, block_number: u32, grace_period: u32) -> bool {
+        let grace_period_ended = match self.accepted_block {
+            Some(accepted_block) => block_number > accepted_block.saturating_add(grace_period),
+            None => false
+        };
+        if !grace_period_ended || !self.is_accepted() || self.is_funded() {
+            return false;
+        }
+
+        self.installments.is_empty() || !self.overdue_installments(block_number).is_empty()
+    }
+
+    /// Moves the recorded funding to `amount_forfeited` and returns it, the founder gives it up to the tribe
+    pub fn forfeit(&mut self) -> u128 {
+        let amount = self.amount_funded;
+        self.amount_funded = 0;
+        self.amount_forfeited += amount;
+        amount
+    }
+
+    pub fn amount_forfeited(&self) -> u128 {
+        self.amount_forfeited
+    }
+
+    /// Zeroes `amount_forfeited` and returns it, only a tribe that never activates gives forfeited funds back
+    pub fn release_forfeited(&mut self) -> u128 {
+        let amount = self.amount_forfeited;
+        self.amount_forfeited = 0;
+        amount
+    }
+
+    /// Moves a pending or accepted founder to `VoteAction::Rejected`, a rejection is final
+    pub fn reject(&mut self) -> Result<(), TribeError> {
+        if self.is_rejected() {
+            return Err(TribeError::FounderRejectedInvitation);
+        }
+        self.vote_action = VoteAction::Rejected;
+        Ok(())
+    }
+
     pub fn has_funds(&self) -> bool {
         self.amount_funded > 0
     }
 
+    pub fn amount_funded(&self) -> u128 {
+        self.amount_funded
+    }
+
+    /// Reduces the recorded funding by `amount`. Returns the remaining total funded
+    pub fn withdraw(&mut self, amount: u128) -> Result<u128, TribeError> {
+        if amount == 0 {
+            return Err(TribeError::WithdrawalAmountMustBeGreaterThanZero);
+        }
+        if amount > self.amount_funded {
+            return Err(TribeError::WithdrawalExceedsAmountFunded);
+        }
+
+        self.amount_funded -= amount;
+        Ok(self.amount_funded)
+    }
+
+    /// Records a new `amount_promised` that only takes effect once the founder calls `confirm_amount`
+    pub fn propose_amount(&mut self, amount_promised: u128) -> Result<(), TribeError> {
+        if amount_promised == 0 {
+            return Err(TribeError::AmountPromisedIsZero);
+        }
+        if !self.installments.is_empty() {
+            return Err(TribeError::InstallmentPlanIsFixed);
+        }
+        if self.is_rejected() {
+            return Err(TribeError::FounderRejectedInvitation);
+        }
+
+        self.amount_proposed = amount_promised;
+        Ok(())
+    }
+
+    /// Applies the proposed `amount_promised`. Returns any funding above the new promise that must be sent back
+    pub fn confirm_amount(&mut self) -> Result<u128, TribeError> {
+        if !self.has_amendment_pending() {
+            return Err(TribeError::NoAmendmentPending);
+        }
+
+        self.amount_promised = self.amount_proposed;
+        self.amount_proposed = 0;
+
+        let excess = self.amount_funded.saturating_sub(self.amount_promised);
+        self.amount_funded -= excess;
+        Ok(excess)
+    }
+
+    pub fn installments(&self) -> &[Installment] {
+        &self.installments
+    }
+
+    /// Installments are paid in order, an installment is paid once `amount_funded` covers it and every earlier one
+    pub fn installments_paid(&self) -> u32 {
+        let mut covered: u128 = 0;
+        let mut paid = 0;
+        for installment in self.installments.iter() {
+            covered += installment.amount;
+            if covered > self.amount_funded {
+                break;
+            }
+            paid += 1;
+        }
+        paid
+    }
+
+    /// Indexes of the unpaid installments whose `due_block` is before `block_number`
+    pub fn overdue_installments(&self, block_number: u32) -> Vec<u32> {
+        let paid = self.installments_paid() as usize;
+        self.installments.iter()
+            .enumerate()
+            .skip(paid)
+            .filter(|(_, installment)| installment.due_block < block_number)
+            .map(|(index, _)| index as u32)
+            .collect()
+    }
+
+    /// Records `new_account` as the account that may take over this seat, replacing any earlier proposal
+    pub fn propose_seat_transfer(&mut self, new_account: AccountId) {
+        self.seat_transfer_to = Some(new_account);
+    }
+
+    pub fn seat_transfer_to(&self) -> Option<AccountId> {
+        self.seat_transfer_to
+    }
+
+    /// Moves the seat to `new_account`, which must be the account proposed by `propose_seat_transfer`
+    pub fn complete_seat_transfer(&mut self, new_account: AccountId) -> Result<(), TribeError> {
+        if self.seat_transfer_to != Some(new_account) {
+            return Err(TribeError::NoSeatTransferPending);
+        }
+
+        self.id = new_account;
+        self.seat_transfer_to = None;
+        Ok(())
+    }
+
+    pub fn has_amendment_pending(&self) -> bool {
+        self.amount_proposed > 0
+    }
+
+    /// Zeroes the recorded funding and returns the amount that should be sent back to the founder
+    pub fn refund(&mut self) -> u128 {
+        let amount = self.amount_funded + self.release_refundable();
+        self.amount_funded = 0;
+        amount
+    }
+
     pub fn has_pending_activity(&self) -> bool {
 
-        if self.vote_action == FOUNDER_PENDING  {
+        if self.vote_action == VoteAction::Pending  {
             return self.required;
         } 
         else if self.is_rejected() || self.is_funded() {
@@ -76,7 +336,7 @@ impl Founder {
     }
 
     pub fn is_accepted(&self) -> bool {
-       self.vote_action == FOUNDER_ACCEPTED
+       self.vote_action == VoteAction::Accepted
     }
 
     pub fn is_funded(&self) -> bool {
@@ -84,26 +344,29 @@ impl Founder {
     }
 
     pub fn is_rejected(&self) -> bool {
-        self.vote_action == FOUNDER_REJECTED
-    }
-
-    pub fn describe(&self) -> String { 
-
-        ink_prelude::format!(r#"{{
-    "initial": {},
-    "required": {},
-    "rejected": {},
-    "completed": {},
-    "amount_promised": {},
-    "amount_funded": {}
-}}"#, 
-          self.initial,
-          self.required,
-          self.is_rejected(),
-          !self.has_pending_activity(),
-          self.amount_promised,
-          self.amount_funded
-      )
+        self.vote_action == VoteAction::Rejected
+    }
+
+    pub fn info(&self) -> FounderInfo {
+        FounderInfo {
+            id: self.id,
+            initial: self.initial,
+            required: self.required,
+            vote_action: self.vote_action,
+            amount_promised: self.amount_promised,
+            amount_funded: self.amount_funded,
+            amount_proposed: if self.has_amendment_pending() { Some(self.amount_proposed) } else { None },
+            amount_refundable: self.amount_refundable,
+            amount_forfeited: self.amount_forfeited,
+            accepted_block: self.accepted_block,
+            installments: self.installments.clone(),
+            installments_paid: self.installments_paid(),
+            completed: !self.has_pending_activity(),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        self.info().to_json()
     }
 }
 
@@ -183,6 +446,38 @@ mod founder_tests {
         };
     }
 
+//***************************** accept() / reject() ***************************
+    //from, action, expected result, expected vote
+    macro_rules! founder_vote_transition {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let alice = AccountId::from([0x0; 32]);
+                let (from, accept, expected, expected_vote) = $value;
+                let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+                founder.vote_action = from;
+
+                //ACT
+                let result = if accept { founder.accept(0) } else { founder.reject() };
+
+                //ASSERT
+                assert_eq!(expected, result);
+                assert_eq!(expected_vote, founder.vote_action());
+            }
+        )*
+        }
+    }
+    founder_vote_transition! {
+        pending_accept: (VoteAction::Pending, true, Ok(()), VoteAction::Accepted),
+        pending_reject: (VoteAction::Pending, false, Ok(()), VoteAction::Rejected),
+        accepted_accept: (VoteAction::Accepted, true, Ok(()), VoteAction::Accepted),
+        accepted_reject: (VoteAction::Accepted, false, Ok(()), VoteAction::Rejected),
+        rejected_accept: (VoteAction::Rejected, true, Err(TribeError::FounderRejectedInvitation), VoteAction::Rejected),
+        rejected_reject: (VoteAction::Rejected, false, Err(TribeError::FounderRejectedInvitation), VoteAction::Rejected),
+    }
+
 //***************************** fund() ***************************
     #[ink::test]
     fn fund_should_fail_when_tribe_is_not_accepted() {
@@ -202,7 +497,7 @@ mod founder_tests {
         //ASSIGN
         let alice = AccountId::from([0x0; 32]);
         let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
-        founder.vote_action = FOUNDER_REJECTED;
+        founder.vote_action = VoteAction::Rejected;
 
         //ACT
         match founder.fund(2000) {
@@ -229,13 +524,14 @@ mod founder_tests {
         //ASSIGN
         let alice = AccountId::from([0x0; 32]); 
         let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
-        founder.vote_action = FOUNDER_ACCEPTED;
+        founder.vote_action = VoteAction::Accepted;
 
         //ACT
-        let amount = founder.fund(5000).expect("funding ok");
+        let (amount, excess) = founder.fund(5000).expect("funding ok");
 
         //ASSERT
         assert_eq!(amount, 5000);
+        assert_eq!(excess, 0);
     }
 
     #[ink::test]
@@ -243,7 +539,7 @@ mod founder_tests {
         //ASSIGN
         let alice = AccountId::from([0x0; 32]); 
         let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
-        founder.vote_action = FOUNDER_ACCEPTED;
+        founder.vote_action = VoteAction::Accepted;
 
         //ACT
         let round1= founder.fund(2000).expect("funding ok");
@@ -251,18 +547,35 @@ mod founder_tests {
         let round3= founder.fund(2000).expect("funding ok");
 
         //ASSERT
-        assert_eq!(round1, 2000);
-        assert_eq!(round2, 4000);
-        assert_eq!(round3, 6000);
+        assert_eq!(round1, (2000, 0));
+        assert_eq!(round2, (4000, 0));
+        assert_eq!(round3, (5000, 1000));
         assert!(founder.is_funded());
     }
     
+    #[ink::test]
+    fn fund_should_return_excess_over_promise_amount() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+
+        //ACT
+        let (amount, excess) = founder.fund(7500).expect("funding ok");
+
+        //ASSERT
+        assert_eq!(amount, 5000);
+        assert_eq!(excess, 2500);
+        assert_eq!(founder.amount_funded(), 5000);
+        assert!(founder.is_funded());
+    }
+
     #[ink::test]
     fn fund_should_fail_when_founder_already_funded() {
         //ASSIGN
         let alice = AccountId::from([0x0; 32]); 
         let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
-        founder.vote_action = FOUNDER_ACCEPTED;
+        founder.vote_action = VoteAction::Accepted;
 
         //ACT
         assert_eq!(founder.is_funded(), false);
@@ -279,7 +592,7 @@ mod founder_tests {
         //ASSIGN
         let alice = AccountId::from([0x0; 32]); 
         let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
-        founder.vote_action = FOUNDER_ACCEPTED;
+        founder.vote_action = VoteAction::Accepted;
         
         //ACT
         founder.fund(100).expect("funding should be ok");
@@ -298,6 +611,508 @@ mod founder_tests {
         assert_eq!(founder.has_funds(), false)
     }
 
+//***************************** withdraw() ***************************
+    #[ink::test]
+    fn withdraw_should_reduce_amount_funded() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.fund(5000).expect("funding should be ok");
+
+        //ACT
+        let remaining = founder.withdraw(2000).expect("withdraw should be ok");
+
+        //ASSERT
+        assert_eq!(remaining, 3000);
+        assert_eq!(founder.is_funded(), false);
+        assert!(founder.has_pending_activity());
+    }
+
+    #[ink::test]
+    fn withdraw_should_fail_with_zero_amount() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.fund(1000).expect("funding should be ok");
+
+        //ACT
+        match founder.withdraw(0) {
+            Ok(_) => assert!(false, "Should not have passed"),
+            Err(err) => assert_eq!(err, TribeError::WithdrawalAmountMustBeGreaterThanZero)
+        }
+    }
+
+    #[ink::test]
+    fn withdraw_should_fail_when_amount_exceeds_funding() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.fund(1000).expect("funding should be ok");
+
+        //ACT
+        match founder.withdraw(1001) {
+            Ok(_) => assert!(false, "Should not have passed"),
+            Err(err) => assert_eq!(err, TribeError::WithdrawalExceedsAmountFunded)
+        }
+        assert_eq!(founder.amount_funded(), 1000);
+    }
+
+//***************************** propose_amount() / confirm_amount() ***************************
+    #[ink::test]
+    fn propose_amount_should_fail_when_amount_is_zero() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+
+        //ACT
+        match founder.propose_amount(0) {
+            Ok(_) => assert!(false, "Should not have passed"),
+            Err(err) => assert_eq!(err, TribeError::AmountPromisedIsZero)
+        }
+    }
+
+    #[ink::test]
+    fn propose_amount_should_fail_when_founder_rejected_tribe() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Rejected;
+
+        //ACT
+        match founder.propose_amount(3000) {
+            Ok(_) => assert!(false, "Should not have passed"),
+            Err(err) => assert_eq!(err, TribeError::FounderRejectedInvitation)
+        }
+    }
+
+    #[ink::test]
+    fn propose_amount_should_not_change_promise_until_confirmed() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+
+        //ACT
+        founder.propose_amount(3000).expect("proposal should be ok");
+
+        //ASSERT
+        assert!(founder.has_amendment_pending());
+        assert_eq!(founder.amount_promised, 5000);
+    }
+
+    #[ink::test]
+    fn confirm_amount_should_fail_without_proposal() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+
+        //ACT
+        match founder.confirm_amount() {
+            Ok(_) => assert!(false, "Should not have passed"),
+            Err(err) => assert_eq!(err, TribeError::NoAmendmentPending)
+        }
+    }
+
+    #[ink::test]
+    fn confirm_amount_should_apply_proposal_and_return_excess() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.fund(4000).expect("funding should be ok");
+        founder.propose_amount(3000).expect("proposal should be ok");
+
+        //ACT
+        let excess = founder.confirm_amount().expect("confirm should be ok");
+
+        //ASSERT
+        assert_eq!(excess, 1000);
+        assert_eq!(founder.amount_promised, 3000);
+        assert_eq!(founder.amount_funded(), 3000);
+        assert!(founder.is_funded());
+        assert_eq!(founder.has_amendment_pending(), false);
+    }
+
+    #[ink::test]
+    fn confirm_amount_should_reopen_funding_when_promise_increases() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.fund(5000).expect("funding should be ok");
+        founder.propose_amount(8000).expect("proposal should be ok");
+
+        //ACT
+        let excess = founder.confirm_amount().expect("confirm should be ok");
+
+        //ASSERT
+        assert_eq!(excess, 0);
+        assert_eq!(founder.is_funded(), false);
+        assert!(founder.has_pending_activity());
+    }
+
+//***************************** is_lagging() / forfeit() ***************************
+    //accepted_block, funded, block_number, expected
+    macro_rules! founder_is_lagging {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let alice = AccountId::from([0x0; 32]);
+                let (accepted_block, funded, block_number, expected): (Option<u32>, u128, u32, bool) = $value;
+                let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+                if let Some(accepted_block) = accepted_block {
+                    founder.accept(accepted_block).expect("ok");
+                }
+                if funded > 0 {
+                    founder.fund(funded).expect("ok");
+                }
+
+                //ACT
+                let result = founder.is_lagging(block_number, 5);
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    founder_is_lagging! {
+        is_lagging_pending: (None, 0, 20, false),
+        is_lagging_within_grace_period: (Some(2), 0, 7, false),
+        is_lagging_after_grace_period: (Some(2), 0, 8, true),
+        is_lagging_partially_funded: (Some(2), 1000, 8, true),
+        is_lagging_funded: (Some(2), 5000, 8, false),
+    }
+
+    //funded, block_number, expected
+    macro_rules! founder_with_installments_is_lagging {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let alice = AccountId::from([0x0; 32]);
+                let (funded, block_number, expected): (u128, u32, bool) = $value;
+                let installments = vec![
+                    Installment { amount: 1000, due_block: 10 },
+                    Installment { amount: 4000, due_block: 20 },
+                ];
+                let mut founder = Founder::with_installments(alice, true, installments).expect("expected founder");
+                founder.accept(2).expect("ok");
+                if funded > 0 {
+                    founder.fund(funded).expect("ok");
+                }
+
+                //ACT
+                let result = founder.is_lagging(block_number, 5);
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    founder_with_installments_is_lagging! {
+        is_lagging_installments_unfunded_before_first_due: (0, 8, false),
+        is_lagging_installments_unfunded_after_first_due: (0, 11, true),
+        is_lagging_installments_on_schedule: (1000, 15, false),
+        is_lagging_installments_on_schedule_at_last_due: (1000, 20, false),
+        is_lagging_installments_overdue: (1000, 21, true),
+        is_lagging_installments_overdue_within_grace_period: (0, 7, false),
+        is_lagging_installments_funded: (5000, 21, false),
+    }
+
+    #[ink::test]
+    fn accept_should_keep_first_accepted_block() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.accept(3).expect("ok");
+
+        //ACT
+        founder.accept(9).expect("ok");
+
+        //ASSERT
+        assert_eq!(founder.accepted_block(), Some(3));
+    }
+
+    #[ink::test]
+    fn forfeit_should_return_funded_amount_and_clear_funds() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.accept(0).expect("ok");
+        founder.fund(2000).expect("funding should be ok");
+
+        //ACT
+        let forfeited = founder.forfeit();
+
+        //ASSERT
+        assert_eq!(forfeited, 2000);
+        assert_eq!(founder.has_funds(), false);
+        assert_eq!(founder.refund(), 0);
+        assert_eq!(founder.amount_forfeited(), 2000);
+        assert_eq!(founder.release_forfeited(), 2000);
+        assert_eq!(founder.amount_forfeited(), 0);
+    }
+
+//***************************** seat transfer ***************************
+    #[ink::test]
+    fn complete_seat_transfer_should_fail_for_other_account() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let bob = AccountId::from([0x1; 32]);
+        let charlie = AccountId::from([0x2; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.propose_seat_transfer(bob);
+
+        //ACT
+        let result = founder.complete_seat_transfer(charlie);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::NoSeatTransferPending));
+        assert_eq!(founder.id, alice);
+    }
+
+    #[ink::test]
+    fn complete_seat_transfer_should_keep_founder_state() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let bob = AccountId::from([0x1; 32]);
+        let mut founder = Founder::initial_founder(alice, 5000).expect("expected founder");
+        founder.accept(2).expect("ok");
+        founder.fund(3000).expect("funding should be ok");
+        founder.propose_seat_transfer(bob);
+
+        //ACT
+        founder.complete_seat_transfer(bob).expect("transfer should be ok");
+
+        //ASSERT
+        assert_eq!(founder.id, bob);
+        assert!(founder.initial);
+        assert!(founder.is_accepted());
+        assert_eq!(founder.amount_funded(), 3000);
+        assert_eq!(founder.accepted_block(), Some(2));
+        assert_eq!(founder.seat_transfer_to(), None);
+    }
+
+//***************************** installments ***************************
+    //installments, expected
+    macro_rules! founder_with_installments_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let alice = AccountId::from([0x0; 32]);
+                let (installments, expected): (Vec<(u128, u32)>, Result<u128, TribeError>) = $value;
+                let installments = installments.into_iter().map(|(amount, due_block)| Installment { amount, due_block }).collect();
+
+                //ACT
+                let result = Founder::with_installments(alice, true, installments).map(|founder| founder.amount_promised);
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    founder_with_installments_tests! {
+        with_installments_single: (vec![(5000, 10)], Ok(5000)),
+        with_installments_sums_amounts: (vec![(1000, 5), (2000, 8), (3000, 10)], Ok(6000)),
+        with_installments_empty: (vec![], Err(TribeError::InvalidInstallmentPlan)),
+        with_installments_zero_amount: (vec![(1000, 5), (0, 8)], Err(TribeError::InvalidInstallmentPlan)),
+        with_installments_same_due_block: (vec![(1000, 5), (2000, 5)], Err(TribeError::InvalidInstallmentPlan)),
+        with_installments_decreasing_due_block: (vec![(1000, 8), (2000, 5)], Err(TribeError::InvalidInstallmentPlan)),
+    }
+
+    #[ink::test]
+    fn installments_should_be_paid_in_order() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let installments = vec![Installment { amount: 1000, due_block: 5 }, Installment { amount: 2000, due_block: 8 }];
+        let mut founder = Founder::with_installments(alice, true, installments).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+
+        //ACT
+        let paid_before = founder.installments_paid();
+        founder.fund(2500).expect("funding should be ok");
+
+        //ASSERT
+        assert_eq!(paid_before, 0);
+        assert_eq!(founder.installments_paid(), 1);
+        founder.fund(500).expect("funding should be ok");
+        assert_eq!(founder.installments_paid(), 2);
+    }
+
+    #[ink::test]
+    fn overdue_installments_should_only_list_unpaid_installments_past_due() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let installments = vec![
+            Installment { amount: 1000, due_block: 5 },
+            Installment { amount: 2000, due_block: 8 },
+            Installment { amount: 3000, due_block: 12 },
+        ];
+        let mut founder = Founder::with_installments(alice, true, installments).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.fund(1000).expect("funding should be ok");
+
+        //ACT
+        let on_time = founder.overdue_installments(8);
+        let overdue = founder.overdue_installments(13);
+
+        //ASSERT
+        assert_eq!(on_time, Vec::<u32>::new());
+        assert_eq!(overdue, vec![1, 2]);
+    }
+
+    #[ink::test]
+    fn propose_amount_should_fail_with_installment_plan() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::with_installments(alice, true, vec![Installment { amount: 1000, due_block: 5 }]).expect("expected founder");
+
+        //ACT
+        let result = founder.propose_amount(2000);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::InstallmentPlanIsFixed));
+    }
+
+//***************************** contribute() / scale_back() ***************************
+    #[ink::test]
+    fn contribute_should_fail_when_founder_pending() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+
+        //ACT
+        let result = founder.contribute(1000);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::FounderVoteActionPending));
+    }
+
+    #[ink::test]
+    fn contribute_should_not_cap_at_amount_promised() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+
+        //ACT
+        founder.contribute(4000).expect("contribution should be ok");
+        let total = founder.contribute(4000).expect("contribution should be ok");
+
+        //ASSERT
+        assert_eq!(total, 8000);
+        assert_eq!(founder.amount_funded(), 8000);
+    }
+
+    #[ink::test]
+    fn scale_back_should_move_pro_rata_excess_to_refundable() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.contribute(6000).expect("contribution should be ok");
+
+        //ACT
+        let excess = founder.scale_back(10000, 15000).expect("scale back should be ok");
+
+        //ASSERT
+        assert_eq!(excess, 2000);
+        assert_eq!(founder.amount_funded(), 4000);
+        assert_eq!(founder.amount_refundable(), 2000);
+        assert_eq!(founder.release_refundable(), 2000);
+        assert_eq!(founder.amount_refundable(), 0);
+    }
+
+    #[ink::test]
+    fn scale_back_should_have_no_effect_within_hard_cap() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.contribute(6000).expect("contribution should be ok");
+
+        //ACT
+        let excess = founder.scale_back(10000, 10000).expect("scale back should be ok");
+
+        //ASSERT
+        assert_eq!(excess, 0);
+        assert_eq!(founder.amount_funded(), 6000);
+    }
+
+    #[ink::test]
+    fn scale_back_should_not_overflow_on_large_contributions() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.contribute(u128::MAX / 2).expect("contribution should be ok");
+
+        //ACT
+        let excess = founder.scale_back(u128::MAX / 2, u128::MAX).expect("scale back should be ok");
+
+        //ASSERT
+        assert_eq!(excess, 1 << 126);
+        assert_eq!(founder.amount_funded(), (1 << 126) - 1);
+    }
+
+//***************************** refund() ***************************
+    #[ink::test]
+    fn refund_should_return_funded_amount_and_clear_funds() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.fund(3000).expect("funding should be ok");
+
+        //ACT
+        let refunded = founder.refund();
+
+        //ASSERT
+        assert_eq!(refunded, 3000);
+        assert_eq!(founder.amount_funded(), 0);
+        assert_eq!(founder.has_funds(), false);
+    }
+
+    #[ink::test]
+    fn refund_should_include_refundable_amount() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+        founder.vote_action = VoteAction::Accepted;
+        founder.contribute(6000).expect("contribution should be ok");
+        founder.scale_back(10000, 15000).expect("scale back should be ok");
+
+        //ACT
+        let refunded = founder.refund();
+
+        //ASSERT
+        assert_eq!(refunded, 6000);
+        assert_eq!(founder.amount_refundable(), 0);
+    }
+
+    #[ink::test]
+    fn refund_should_return_zero_without_funds() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
+
+        //ACT
+        let refunded = founder.refund();
+
+        //ASSERT
+        assert_eq!(refunded, 0);
+    }
+
     //required, vote_account, promised, funded, expected
     macro_rules! founder_has_pending_activity {
         ($($name:ident: $value:expr,)*) => {
@@ -309,7 +1124,7 @@ mod founder_tests {
                 let (required, vote_action, promised, funded, expected) = $value;
                 let mut founder = Founder::new(alice, required, promised).expect("expected founder");
                 if (funded > 0) {
-                    founder.vote_action = FOUNDER_ACCEPTED;
+                    founder.vote_action = VoteAction::Accepted;
                     founder.fund(funded).expect("ok");
                 }
                 founder.vote_action = vote_action;
@@ -324,17 +1139,17 @@ mod founder_tests {
         }
     }
     founder_has_pending_activity! {
-        required_pending_5000_0: (true, FOUNDER_PENDING, 5000, 0, true),
-        required_accepted_5000_0: (true, FOUNDER_ACCEPTED, 5000, 0, true),
-        required_accepted_5000_5000: (true, FOUNDER_ACCEPTED, 5000, 5000, false),
-        required_rejected_5000_0: (true, FOUNDER_REJECTED, 5000, 0, false),
-        required_rejected_5000_5000: (true, FOUNDER_REJECTED, 5000, 5000, false),
-
-        optional_pending_5000_0: (false, FOUNDER_PENDING, 5000, 0, false),
-        optional_accepted_5000_0: (false, FOUNDER_ACCEPTED, 5000, 0, true),
-        optional_accepted_5000_5000: (false, FOUNDER_ACCEPTED, 5000, 5000, false),
-        optional_rejected_5000_0: (false, FOUNDER_REJECTED, 5000, 0, false),
-        optional_rejected_5000_5000: (false, FOUNDER_REJECTED, 5000, 5000, false),
+        required_pending_5000_0: (true, VoteAction::Pending, 5000, 0, true),
+        required_accepted_5000_0: (true, VoteAction::Accepted, 5000, 0, true),
+        required_accepted_5000_5000: (true, VoteAction::Accepted, 5000, 5000, false),
+        required_rejected_5000_0: (true, VoteAction::Rejected, 5000, 0, false),
+        required_rejected_5000_5000: (true, VoteAction::Rejected, 5000, 5000, false),
+
+        optional_pending_5000_0: (false, VoteAction::Pending, 5000, 0, false),
+        optional_accepted_5000_0: (false, VoteAction::Accepted, 5000, 0, true),
+        optional_accepted_5000_5000: (false, VoteAction::Accepted, 5000, 5000, false),
+        optional_rejected_5000_0: (false, VoteAction::Rejected, 5000, 0, false),
+        optional_rejected_5000_5000: (false, VoteAction::Rejected, 5000, 5000, false),
     }
     
     macro_rules! founder_is_accepted {
@@ -360,13 +1175,13 @@ mod founder_tests {
         }
     }
     founder_is_accepted! {
-        is_accpted_true_with_required_founder_accepted: (true, FOUNDER_ACCEPTED, true),
-        is_accpted_false_with_required_founder_pending: (true, FOUNDER_PENDING, false),
-        is_accpted_false_with_required_founder_rejected: (true, FOUNDER_REJECTED, false),
+        is_accpted_true_with_required_founder_accepted: (true, VoteAction::Accepted, true),
+        is_accpted_false_with_required_founder_pending: (true, VoteAction::Pending, false),
+        is_accpted_false_with_required_founder_rejected: (true, VoteAction::Rejected, false),
 
-        is_accpted_true_with_founder_accepted: (false, FOUNDER_ACCEPTED, true),
-        is_accpted_false_with_founder_pending: (false, FOUNDER_PENDING, false),
-        is_accpted_false_with_founder_rejected: (false, FOUNDER_REJECTED, false),
+        is_accpted_true_with_founder_accepted: (false, VoteAction::Accepted, true),
+        is_accpted_false_with_founder_pending: (false, VoteAction::Pending, false),
+        is_accpted_false_with_founder_rejected: (false, VoteAction::Rejected, false),
     }      
 
     #[ink::test]
@@ -374,24 +1189,24 @@ mod founder_tests {
         //ASSIGN
         let alice = AccountId::from([0x0; 32]); 
         let mut founder = Founder::new(alice, true, 5000).expect("expected founder");
-        founder.vote_action = FOUNDER_ACCEPTED;
+        founder.vote_action = VoteAction::Accepted;
 
         //ACT
         assert_eq!(founder.is_funded(), false);
 
-        assert_eq!(founder.fund(1000).expect("funding should pass"), 1000);
+        assert_eq!(founder.fund(1000).expect("funding should pass"), (1000, 0));
         assert_eq!(founder.is_funded(), false);
 
-        assert_eq!(founder.fund(1000).expect("funding should pass"), 2000);
+        assert_eq!(founder.fund(1000).expect("funding should pass"), (2000, 0));
         assert_eq!(founder.is_funded(), false);
 
-        assert_eq!(founder.fund(1000).expect("funding should pass"), 3000);
+        assert_eq!(founder.fund(1000).expect("funding should pass"), (3000, 0));
         assert_eq!(founder.is_funded(), false);
 
-        assert_eq!(founder.fund(1000).expect("funding should pass"), 4000);
+        assert_eq!(founder.fund(1000).expect("funding should pass"), (4000, 0));
         assert_eq!(founder.is_funded(), false);
 
-        assert_eq!(founder.fund(1000).expect("funding should pass"), 5000);
+        assert_eq!(founder.fund(1000).expect("funding should pass"), (5000, 0));
         assert!(founder.is_funded());
     }
 
@@ -416,8 +1231,8 @@ mod founder_tests {
         }
     }
     founder_is_rejected! {
-        founder_is_rejected_pending: (FOUNDER_PENDING, false),
-        founder_is_rejected_accepted: (FOUNDER_ACCEPTED, false),
-        founder_is_rejected_rejected: (FOUNDER_REJECTED, true),    
+        founder_is_rejected_pending: (VoteAction::Pending, false),
+        founder_is_rejected_accepted: (VoteAction::Accepted, false),
+        founder_is_rejected_rejected: (VoteAction::Rejected, true),    
     }
 }
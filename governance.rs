@@ -0,0 +1,193 @@
+use ink_env::{AccountId, Hash};
+use ink_storage::traits::{SpreadLayout, PackedLayout};
+use crate::{
+    config::GovernanceConfig,
+    errors::TribeError
+};
+
+/// Outcome of a governance proposal
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum ProposalStatus {
+    Open,
+    Passed,
+    Failed
+}
+
+/// A question put to the founders of an active tribe, decided by weighted vote once `end_block` has passed
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct Proposal {
+    pub id: u32,
+    pub proposer: AccountId,
+    /// Hash of the description agreed off chain
+    pub description_hash: Hash,
+    /// Last block in which votes are accepted
+    pub end_block: u32,
+    pub weight_for: u128,
+    pub weight_against: u128,
+    /// Voting weight of every founder able to vote when the proposal was submitted
+    pub total_weight: u128,
+    pub status: ProposalStatus,
+}
+
+impl Proposal {
+
+    pub fn new(id: u32, proposer: AccountId, description_hash: Hash, end_block: u32, total_weight: u128) -> Self {
+        Self {
+            id,
+            proposer,
+            description_hash,
+            end_block,
+            weight_for: 0,
+            weight_against: 0,
+            total_weight,
+            status: ProposalStatus::Open,
+        }
+    }
+
+    /// Counts `weight` for or against the proposal at `block_number`
+    pub fn vote(&mut self, block_number: u32, approve: bool, weight: u128) -> Result<(), TribeError> {
+        if self.status != ProposalStatus::Open {
+            return Err(TribeError::ProposalNotOpen);
+        }
+        if block_number > self.end_block {
+            return Err(TribeError::VotingPeriodEnded);
+        }
+        if weight == 0 {
+            return Err(TribeError::NoVotingWeight);
+        }
+
+        if approve {
+            self.weight_for += weight;
+        } else {
+            self.weight_against += weight;
+        }
+
+        Ok(())
+    }
+
+    /// Decides the proposal once its voting period has ended. It passes when the weight cast meets the `quorum`
+    /// and more than `approval_threshold` percent of it approves, otherwise it fails
+    pub fn close(&mut self, block_number: u32, governance: &GovernanceConfig) -> Result<ProposalStatus, TribeError> {
+        if self.status != ProposalStatus::Open {
+            return Err(TribeError::ProposalNotOpen);
+        }
+        if block_number <= self.end_block {
+            return Err(TribeError::VotingPeriodNotEnded);
+        }
+
+        let cast = self.weight_for + self.weight_against;
+        let quorum_met = cast > 0 && cast.saturating_mul(100) >= self.total_weight.saturating_mul(governance.quorum as u128);
+        let approved = self.weight_for.saturating_mul(100) > cast.saturating_mul(governance.approval_threshold as u128);
+
+        self.status = if quorum_met && approved {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Failed
+        };
+
+        Ok(self.status)
+    }
+}
+
+///
+/// Governance Unit Tests
+///
+#[cfg(test)]
+mod governance_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    fn proposal(total_weight: u128) -> Proposal {
+        let alice = AccountId::from([0x0; 32]);
+        Proposal::new(0, alice, Hash::from([0x9; 32]), 10, total_weight)
+    }
+
+    #[ink::test]
+    fn vote_should_fail_after_voting_period() {
+        //ASSIGN
+        let mut proposal = proposal(1000);
+
+        //ACT
+        let result = proposal.vote(11, true, 500);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::VotingPeriodEnded));
+    }
+
+    #[ink::test]
+    fn vote_should_fail_without_weight() {
+        //ASSIGN
+        let mut proposal = proposal(1000);
+
+        //ACT
+        let result = proposal.vote(10, true, 0);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::NoVotingWeight));
+    }
+
+    #[ink::test]
+    fn close_should_fail_within_voting_period() {
+        //ASSIGN
+        let mut proposal = proposal(1000);
+
+        //ACT
+        let result = proposal.close(10, &GovernanceConfig::default());
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::VotingPeriodNotEnded));
+        assert_eq!(proposal.status, ProposalStatus::Open);
+    }
+
+    #[ink::test]
+    fn close_should_fail_once_closed() {
+        //ASSIGN
+        let mut proposal = proposal(1000);
+        proposal.close(11, &GovernanceConfig::default()).expect("close should be ok");
+
+        //ACT
+        let result = proposal.close(12, &GovernanceConfig::default());
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::ProposalNotOpen));
+    }
+
+    //weight_for, weight_against, quorum, approval_threshold, expected
+    macro_rules! proposal_close {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (weight_for, weight_against, quorum, approval_threshold, expected): (u128, u128, u8, u8, ProposalStatus) = $value;
+                let mut proposal = proposal(1000);
+                if weight_for > 0 {
+                    proposal.vote(5, true, weight_for).expect("vote should be ok");
+                }
+                if weight_against > 0 {
+                    proposal.vote(5, false, weight_against).expect("vote should be ok");
+                }
+                let governance = GovernanceConfig { quorum, approval_threshold, ..GovernanceConfig::default() };
+
+                //ACT
+                let status = proposal.close(11, &governance).expect("close should be ok");
+
+                //ASSERT
+                assert_eq!(status, expected);
+            }
+        )*
+        }
+    }
+    proposal_close! {
+        close_without_votes: (0, 0, 50, 50, ProposalStatus::Failed),
+        close_below_quorum: (400, 0, 50, 50, ProposalStatus::Failed),
+        close_at_quorum: (500, 0, 50, 50, ProposalStatus::Passed),
+        close_tie_is_not_approved: (300, 300, 50, 50, ProposalStatus::Failed),
+        close_majority_approves: (301, 300, 50, 50, ProposalStatus::Passed),
+        close_below_supermajority: (600, 400, 50, 60, ProposalStatus::Failed),
+        close_above_supermajority: (601, 399, 50, 60, ProposalStatus::Passed),
+        close_any_approval: (1, 999, 100, 0, ProposalStatus::Passed),
+    }
+}
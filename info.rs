@@ -0,0 +1,128 @@
+use ink_env::AccountId;
+use ink_prelude::{string::String, vec::Vec};
+use crate::{
+    founder::Installment,
+    status::{TribeStatus, VoteAction}
+};
+
+/// Read-only view of a tribe returned by `get_tribe_info`
+#[derive(scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct TribeInfo {
+    pub name: String,
+    pub status: TribeStatus,
+    pub founding_deadline: u32,
+    pub founder_count: u32,
+    pub total_funded: u128,
+    pub roster_frozen: bool,
+}
+
+impl TribeInfo {
+
+    /// Legacy JSON shape returned by `get_tribe`
+    pub fn to_json(&self) -> String {
+        ink_prelude::format!(r#"{{
+    "name": "{}",
+    "enabled": {},
+    "defunct": {}
+}}"#,
+            escape_json(&self.name),
+            self.status == TribeStatus::Active,
+            self.status == TribeStatus::Defunct || self.status == TribeStatus::Dissolved
+        )
+    }
+}
+
+/// Read-only view of a founder returned by `get_founder_info`
+#[derive(scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct FounderInfo {
+    pub id: AccountId,
+    pub initial: bool,
+    pub required: bool,
+    pub vote_action: VoteAction,
+    pub amount_promised: u128,
+    pub amount_funded: u128,
+    pub amount_proposed: Option<u128>,
+    pub amount_refundable: u128,
+    pub amount_forfeited: u128,
+    pub accepted_block: Option<u32>,
+    pub installments: Vec<Installment>,
+    pub installments_paid: u32,
+    pub completed: bool,
+}
+
+impl FounderInfo {
+
+    /// Legacy JSON shape returned by `get_founder_status`
+    pub fn to_json(&self) -> String {
+        ink_prelude::format!(r#"{{
+    "initial": {},
+    "required": {},
+    "rejected": {},
+    "completed": {},
+    "amount_promised": {},
+    "amount_funded": {}
+}}"#,
+            self.initial,
+            self.required,
+            self.vote_action == VoteAction::Rejected,
+            self.completed,
+            self.amount_promised,
+            self.amount_funded
+        )
+    }
+}
+
+/// Escapes `value` so it can be placed between double quotes in a JSON document
+pub fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&ink_prelude::format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+///
+/// Info Unit Tests
+///
+#[cfg(test)]
+mod info_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    //value, expected
+    macro_rules! escape_json_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (value, expected) = $value;
+
+                //ACT
+                let escaped = escape_json(value);
+
+                //ASSERT
+                assert_eq!(escaped, expected);
+            }
+        )*
+        }
+    }
+    escape_json_tests! {
+        escape_json_plain: ("alice's tribe", "alice's tribe"),
+        escape_json_quote: ("the \"best\" tribe", "the \\\"best\\\" tribe"),
+        escape_json_backslash: ("a\\b", "a\\\\b"),
+        escape_json_whitespace: ("a\nb\tc\r", "a\\nb\\tc\\r"),
+        escape_json_control: ("a\u{1}b", "a\\u0001b"),
+        escape_json_unicode: ("tribu ñ", "tribu ñ"),
+    }
+}
@@ -1,120 +1,747 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use ink_lang as ink;
 
+mod config;
 mod errors;
 mod founder;
+mod governance;
+mod info;
+mod math;
+mod member;
+mod psp22;
+mod roles;
+mod status;
+mod treasury;
 
 #[ink::contract]
 mod tribe {
     //use ink_env::{AccountId, return_value};
     use ink_storage::traits::{SpreadAllocate};
     use ink_prelude::{string::String, vec::Vec};
-    use crate::errors::{TribeError};
+    use crate::config::{ActivationPolicy, CommitmentRule, LaggardFunds, TribeConfig, VoteWeighting};
+    use crate::errors::{MyDisplay, TribeError};
     use crate::founder::*;
+    use crate::governance::{Proposal, ProposalStatus};
+    use crate::info::{FounderInfo, TribeInfo};
+    use crate::math::mul_div;
+    use crate::member::Member;
+    use crate::psp22::PSP22Error;
+    use crate::roles::Role;
+    use crate::status::{TribeStatus, VoteAction};
+    use crate::treasury::{SpendProposal, SpendStatus};
+
+    /// Emitted when an inviter invites a new founder
+    #[ink(event)]
+    pub struct FounderInvited {
+        #[ink(topic)]
+        founder: AccountId,
+        amount_promised: u128,
+        required: bool,
+    }
+
+    /// Emitted when an inviter revokes an outstanding invitation
+    #[ink(event)]
+    pub struct FounderRevoked {
+        #[ink(topic)]
+        founder: AccountId,
+    }
+
+    /// Emitted when a founder accepts the tribe
+    #[ink(event)]
+    pub struct FounderAccepted {
+        #[ink(topic)]
+        founder: AccountId,
+    }
+
+    /// Emitted when `founder` misses the installment at index `installment` and is treated as having rejected the tribe
+    #[ink(event)]
+    pub struct InstallmentMissed {
+        #[ink(topic)]
+        founder: AccountId,
+        installment: u32,
+    }
+
+    /// Emitted for every vote to eject `founder`, `votes` is the running total
+    #[ink(event)]
+    pub struct EjectVoteCast {
+        #[ink(topic)]
+        voter: AccountId,
+        #[ink(topic)]
+        founder: AccountId,
+        votes: u32,
+    }
+
+    /// Emitted when `founder` is ejected for not funding in time, `forfeited` is the amount that stays with the tribe
+    #[ink(event)]
+    pub struct FounderEjected {
+        #[ink(topic)]
+        founder: AccountId,
+        forfeited: u128,
+    }
+
+    /// Emitted when an inviter invites `founder` in place of `rejected_founder`
+    #[ink(event)]
+    pub struct FounderReplaced {
+        #[ink(topic)]
+        rejected_founder: AccountId,
+        #[ink(topic)]
+        founder: AccountId,
+    }
+
+    /// Emitted when the initial founder proposes `nominee` to take over the initial founder role
+    #[ink(event)]
+    pub struct InitialFounderProposed {
+        #[ink(topic)]
+        founder: AccountId,
+        #[ink(topic)]
+        nominee: AccountId,
+    }
+
+    /// Emitted when `founder` takes over the initial founder role from `previous`
+    #[ink(event)]
+    pub struct InitialFounderChanged {
+        #[ink(topic)]
+        previous: AccountId,
+        #[ink(topic)]
+        founder: AccountId,
+    }
+
+    /// Emitted once the roster is frozen and no more founders can be invited
+    #[ink(event)]
+    pub struct RosterFrozen {}
+
+    /// Emitted when `founder` rejects the tribe under a rejection penalty, `penalty` is what was redistributed to the other founders
+    #[ink(event)]
+    pub struct RejectionPenalized {
+        #[ink(topic)]
+        founder: AccountId,
+        penalty: u128,
+    }
+
+    /// Emitted when `founder` proposes `new_account` to take over their seat
+    #[ink(event)]
+    pub struct FounderSeatTransferProposed {
+        #[ink(topic)]
+        founder: AccountId,
+        #[ink(topic)]
+        new_account: AccountId,
+    }
+
+    /// Emitted when the seat held by `from` is taken over by `to`
+    #[ink(event)]
+    pub struct FounderSeatTransferred {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    /// Emitted when a founder rejects the tribe
+    #[ink(event)]
+    pub struct FounderRejected {
+        #[ink(topic)]
+        founder: AccountId,
+        required: bool,
+    }
+
+    /// Emitted for every accepted funding action, `total_funded` is the founder's running total
+    #[ink(event)]
+    pub struct FounderFunded {
+        #[ink(topic)]
+        founder: AccountId,
+        amount: u128,
+        total_funded: u128,
+    }
+
+    /// Emitted when a founder withdraws part of their contribution, `total_funded` is the founder's remaining total
+    #[ink(event)]
+    pub struct FounderWithdrew {
+        #[ink(topic)]
+        founder: AccountId,
+        amount: u128,
+        total_funded: u128,
+    }
+
+    /// Emitted when an inviter proposes a new `amount_promised` for a founder
+    #[ink(event)]
+    pub struct AmountPromisedProposed {
+        #[ink(topic)]
+        founder: AccountId,
+        amount_promised: u128,
+    }
+
+    /// Emitted when a founder confirms their new `amount_promised`, `refunded` is the funding returned above the new promise
+    #[ink(event)]
+    pub struct AmountPromisedConfirmed {
+        #[ink(topic)]
+        founder: AccountId,
+        amount_promised: u128,
+        refunded: u128,
+    }
+
+    /// Emitted once every founder has settled and the tribe is enabled
+    #[ink(event)]
+    pub struct TribeActivated {
+        total_funded: u128,
+    }
+
+    /// Emitted when the tribe becomes defunct, `founder` is the required founder who rejected it or `None` when the founding deadline expired
+    #[ink(event)]
+    pub struct TribeDefunct {
+        #[ink(topic)]
+        founder: Option<AccountId>,
+    }
+
+    /// Emitted at activation when a founder's contribution is scaled back to fit the `hard_cap`, `total_funded` is what the founder keeps in the tribe
+    #[ink(event)]
+    pub struct ContributionScaledBack {
+        #[ink(topic)]
+        founder: AccountId,
+        amount: u128,
+        total_funded: u128,
+    }
+
+    /// Emitted when a defunct tribe has refunded everyone and is dissolved
+    #[ink(event)]
+    pub struct TribeDissolved {}
+
+    /// Emitted when `proposer` asks to pay `amount` of the tribe's funds to `recipient`
+    #[ink(event)]
+    pub struct SpendProposed {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        proposer: AccountId,
+        recipient: AccountId,
+        amount: u128,
+    }
+
+    /// Emitted when `voter` votes on spend proposal `id`
+    #[ink(event)]
+    pub struct SpendVoteCast {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        voter: AccountId,
+        approve: bool,
+    }
+
+    /// Emitted when an approved spend proposal pays `amount` to `recipient`
+    #[ink(event)]
+    pub struct SpendExecuted {
+        #[ink(topic)]
+        id: u32,
+        recipient: AccountId,
+        amount: u128,
+    }
+
+    /// Emitted when enough founders vote against spend proposal `id` that it can no longer pass
+    #[ink(event)]
+    pub struct SpendRejected {
+        #[ink(topic)]
+        id: u32,
+    }
+
+    /// Emitted when `proposer` submits governance proposal `id`, open for votes up to and including `end_block`
+    #[ink(event)]
+    pub struct ProposalSubmitted {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        proposer: AccountId,
+        end_block: BlockNumber,
+    }
+
+    /// Emitted when `voter` casts `weight` for or against governance proposal `id`
+    #[ink(event)]
+    pub struct ProposalVoteCast {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        voter: AccountId,
+        approve: bool,
+        weight: u128,
+    }
+
+    /// Emitted when governance proposal `id` is decided
+    #[ink(event)]
+    pub struct ProposalClosed {
+        #[ink(topic)]
+        id: u32,
+        status: ProposalStatus,
+    }
+
+    /// Emitted when `member` asks to join an active tribe
+    #[ink(event)]
+    pub struct MembershipRequested {
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    /// Emitted when `approver` approves the membership request of `member`, `approvals` counts the approvals so far
+    #[ink(event)]
+    pub struct MembershipApprovalCast {
+        #[ink(topic)]
+        approver: AccountId,
+        #[ink(topic)]
+        member: AccountId,
+        approvals: u32,
+    }
+
+    /// Emitted when `member` joins the tribe
+    #[ink(event)]
+    pub struct MemberAdded {
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    /// Emitted when `member` or their pending request is removed from the tribe
+    #[ink(event)]
+    pub struct MemberRemoved {
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    /// PSP22 event emitted when `value` shares move from `from` to `to`, `from` is `None` when shares are minted
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
 
-    pub const FOUNDER_REJECTED: i32 = -1;
-    pub const FOUNDER_PENDING: i32 = 0;
-    pub const FOUNDER_ACCEPTED: i32 = 1;
+    /// PSP22 event emitted when `owner` allows `spender` to move `value` of their shares
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+    }
+
+    /// Emitted when `admin` grants `role` to `account`
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        account: AccountId,
+        role: Role,
+        #[ink(topic)]
+        admin: AccountId,
+    }
+
+    /// Emitted when `admin` revokes `role` from `account`
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        account: AccountId,
+        role: Role,
+        #[ink(topic)]
+        admin: AccountId,
+    }
 
     #[ink(storage)]
     #[derive(SpreadAllocate)]
     pub struct TribeContract {
-        /// Stores a single `bool` value on the storage.
-        enabled: bool,
-        defunct: bool,
+        status: TribeStatus,
         name: String,
-        founders: ink_storage::Mapping<u32, Vec<Founder>>
+        founding_deadline: BlockNumber,
+        /// Founder records keyed by their AccountId
+        founders: ink_storage::Mapping<AccountId, Founder>,
+        /// Founder AccountIds in invitation order, from 0 to `founder_count`
+        founder_ids: ink_storage::Mapping<u32, AccountId>,
+        founder_count: u32,
+        /// Seat of every founder, which stays the same when the seat is transferred so votes follow the seat rather than the account
+        founder_seats: ink_storage::Mapping<AccountId, u32>,
+        /// Seats handed out so far, a seat is never reused
+        seat_count: u32,
+        /// Founders that still `has_pending_activity`, activation waits for this to reach 0
+        pending_founder_count: u32,
+        /// Sum of `amount_funded` across all founders
+        total_funded: u128,
+        /// Rules chosen at construction, see `TribeConfig`
+        config: TribeConfig,
+        /// Required founders who have not rejected and are not fully funded
+        required_unfunded_count: u32,
+        /// Optional founders who have not rejected and are fully funded
+        optional_funded_count: u32,
+        /// Sum of `amount_promised` across founders who have not rejected
+        committed_promised: u128,
+        /// Sum of `amount_funded` across founders who have not rejected
+        committed_funded: u128,
+        /// Founders who have rejected, been ejected or missed an installment
+        rejected_founder_count: u32,
+        /// Founders who accepted the tribe and can vote on its proposals
+        accepted_founder_count: u32,
+        /// Sum of `amount_forfeited` across all founders
+        forfeited_funds: u128,
+        /// Sum of `amount_refundable` across all founders
+        refundable_funds: u128,
+        /// `(founder seat, voter seat)` pairs for every vote cast to eject a founder who has not funded in time
+        eject_votes: ink_storage::Mapping<(u32, u32), bool>,
+        /// Votes cast to eject each founder seat
+        eject_vote_count: ink_storage::Mapping<u32, u32>,
+        /// No founder can be invited while this is set, only rejected optional founders can still be replaced
+        roster_frozen: bool,
+        /// Seats of rejected optional founders mapped to the seat of the founder invited in their place
+        replacements: ink_storage::Mapping<u32, u32>,
+        /// Seats of replacement founders mapped to the rejected seat they were invited for
+        replaced_seats: ink_storage::Mapping<u32, u32>,
+        /// Founder proposed by the initial founder to take over the initial founder role
+        initial_founder_nominee: Option<AccountId>,
+        spend_proposals: ink_storage::Mapping<u32, SpendProposal>,
+        spend_proposal_count: u32,
+        /// `(proposal, voter seat)` pairs for every vote cast on a spend proposal
+        spend_votes: ink_storage::Mapping<(u32, u32), bool>,
+        /// Funds paid out by executed spend proposals
+        treasury_spent: u128,
+        proposals: ink_storage::Mapping<u32, Proposal>,
+        proposal_count: u32,
+        /// `(proposal, voter seat)` pairs for every vote cast on a governance proposal
+        proposal_votes: ink_storage::Mapping<(u32, u32), bool>,
+        /// Members of an active tribe and accounts waiting to be approved as one
+        members: ink_storage::Mapping<AccountId, Member>,
+        /// Number of approved members
+        member_count: u32,
+        membership_request_count: u32,
+        /// `(request, approver seat)` pairs for every approval cast on a membership request
+        membership_votes: ink_storage::Mapping<(u32, u32), bool>,
+        /// Roles granted explicitly through `grant_role`
+        roles: ink_storage::Mapping<(AccountId, Role), bool>,
+        /// PSP22 share balances, minted to founders from their `amount_funded` when the tribe activates
+        share_balances: ink_storage::Mapping<AccountId, Balance>,
+        /// `(owner, spender)` share allowances
+        share_allowances: ink_storage::Mapping<(AccountId, AccountId), Balance>,
+        share_supply: Balance,
+        /// Last block in which each account's shares can not leave it because they were used to vote on an open proposal
+        share_locks: ink_storage::Mapping<AccountId, BlockNumber>
     }
 
     impl TribeContract {
-        /// Constructor that initializes the tribe with a given `init_name`, `initial_founder_amount_in_pico_needed` must not be 0
+        /// Constructor that initializes the tribe with a given `init_name`, `initial_founder_amount_in_pico_needed` must not be 0.
+        /// The caller becomes the initial founder and has accepted the tribe, any value transferred is their first contribution
+        /// and whatever exceeds `initial_founder_amount_in_pico_needed` is sent back.
+        /// The tribe must activate by block `founding_deadline`, after which anyone can `finalize_founding`
+        #[ink(constructor, payable)]
+        pub fn new(init_name: String, initial_founder_amount_in_pico_needed: u128, founding_deadline: BlockNumber) -> Self {
+            Self::new_with_founders(init_name, initial_founder_amount_in_pico_needed, founding_deadline, Vec::new())
+        }
+
+        /// Same as `new`, but also invites every `(founder, amount_in_pico, required)` entry of `founders` under the rules of `invite_founder`.
+        /// Constructors can not return a `Result`, so a broken entry aborts the deployment with the `TribeError` description
         #[ink(constructor, payable)]
-        pub fn new(init_name: String, initial_founder_amount_in_pico_needed: u128) -> Self {
+        pub fn new_with_founders(init_name: String, initial_founder_amount_in_pico_needed: u128, founding_deadline: BlockNumber, founders: Vec<(AccountId, u128, bool)>) -> Self {
+            Self::new_with_config(init_name, initial_founder_amount_in_pico_needed, founding_deadline, founders, TribeConfig::default())
+        }
+
+        /// Same as `new_with_founders`, with the rules of the tribe taken from `config` instead of `TribeConfig::default()`.
+        /// Every constructor ends up here; ink 3 constructors can not return a `Result`, so a failed `seed_tribe` reverts the
+        /// deployment with the `TribeError` description. The README lists each error a deployment can fail with
+        #[ink(constructor, payable)]
+        pub fn new_with_config(init_name: String, initial_founder_amount_in_pico_needed: u128, founding_deadline: BlockNumber, founders: Vec<(AccountId, u128, bool)>, config: TribeConfig) -> Self {
             ink_lang::utils::initialize_contract(|contract: &mut Self| {
-                let caller = Self::env().caller();
-                contract.name = init_name;
-                contract.enabled = false;
-                contract.defunct = false;
-
-                contract.founders.insert(0, &ink_prelude::vec![
-                    Founder::initial_founder(caller, initial_founder_amount_in_pico_needed).expect("need initial founder")
-                ]);
+                if let Err(err) = contract.seed_tribe(init_name, initial_founder_amount_in_pico_needed, founding_deadline, &founders, config) {
+                    panic!("{}", err.fmt());
+                }
             })
         }
 
+        /// Stores the initial founder, the invited roster and the endowment of a freshly allocated tribe
+        fn seed_tribe(&mut self, init_name: String, initial_founder_amount_in_pico_needed: u128, founding_deadline: BlockNumber, invitations: &[(AccountId, u128, bool)], config: TribeConfig) -> Result<(), TribeError> {
+            config.validate()?;
+            if founding_deadline <= Self::env().block_number() {
+                return Err(TribeError::InvalidFoundingDeadline);
+            }
+
+            let caller = Self::env().caller();
+            let endowment = Self::env().transferred_value();
+            self.config = config;
+            self.name = init_name;
+            self.status = TribeStatus::Forming;
+            self.founding_deadline = founding_deadline;
+
+            let mut initial_founder = Founder::initial_founder(caller, initial_founder_amount_in_pico_needed)?;
+            initial_founder.accept(Self::env().block_number())?;
+            self.save_founder(&initial_founder);
+
+            for &(potential_founder, amount_in_pico, required) in invitations {
+                if potential_founder == caller {
+                    return Err(TribeError::CanNotInviteInitialFounder);
+                }
+                if self.founders.contains(potential_founder) {
+                    return Err(TribeError::FounderAlreadyInvited);
+                }
+
+                self.save_founder(&Founder::new(potential_founder, required, amount_in_pico)?);
+                Self::env().emit_event(FounderInvited {
+                    founder: potential_founder,
+                    amount_promised: amount_in_pico,
+                    required,
+                });
+            }
+
+            // activation is left to the next founder action so the initial founder can still invite others
+            if endowment > 0 {
+                let (total_funded_amount, excess) = self.credit_funding(&mut initial_founder, endowment)?;
+                if excess > 0 && Self::env().transfer(caller, excess).is_err() {
+                    return Err(TribeError::TransferFailed);
+                }
+                self.save_founder(&initial_founder);
+
+                Self::env().emit_event(FounderFunded {
+                    founder: caller,
+                    amount: endowment - excess,
+                    total_funded: total_funded_amount,
+                });
+            }
+
+            Ok(())
+        }
+
         fn activate_tribe(&mut self) -> Result<(), TribeError> {
-            if self.enabled && !self.defunct {
+            if self.status != TribeStatus::Forming || !self.activation_threshold_met() {
                 return Ok(());
             }
 
-            let all_founders = self.get_founder_list()?;
-            for founder in all_founders {
-                if founder.has_pending_activity() {
-                    return Ok(());
+            if let Some(hard_cap) = self.config.activation_policy.hard_cap() {
+                self.scale_back_contributions(hard_cap)?;
+            }
+
+            self.status.transition(TribeStatus::Active)?;
+            self.mint_shares()?;
+
+            self.env().emit_event(TribeActivated { total_funded: self.total_funded });
+
+            Ok(())
+        }
+
+        /// Mints one share per pico funded to every founder who has not rejected
+        fn mint_shares(&mut self) -> Result<(), TribeError> {
+            for founder in self.get_founder_list()? {
+                let amount = founder.amount_funded();
+                if founder.is_rejected() || amount == 0 {
+                    continue;
                 }
+
+                self.share_balances.insert(founder.id, &(self.balance_of(founder.id) + amount));
+                self.share_supply += amount;
+                self.env().emit_event(Transfer {
+                    from: None,
+                    to: Some(founder.id),
+                    value: amount,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Scales every contribution back pro rata so the tribe keeps at most `hard_cap`, sending the excess back to each founder
+        fn scale_back_contributions(&mut self, hard_cap: u128) -> Result<(), TribeError> {
+            let raised = self.committed_funded;
+            if raised <= hard_cap {
+                return Ok(());
             }
 
-            self.enabled = true;
+            // a founder whose transfer fails keeps the excess as `amount_refundable` and can still `claim_refund`
+            for mut founder in self.get_founder_list()? {
+                if founder.is_rejected() {
+                    continue;
+                }
+                let excess = founder.scale_back(hard_cap, raised)?;
+                if excess == 0 {
+                    continue;
+                }
+                if self.env().transfer(founder.id, excess).is_ok() {
+                    founder.release_refundable();
+                }
+                self.save_founder(&founder);
 
-            //TODO event goes here
+                self.env().emit_event(ContributionScaledBack {
+                    founder: founder.id,
+                    amount: excess,
+                    total_funded: founder.amount_funded(),
+                });
+            }
 
             Ok(())
         }
 
-        fn get_founder_list(&self) -> Result<Vec<Founder>,TribeError> {
-            match self.founders.get(0) {
-                Some(list) => Ok(list),
-                None => Err(TribeError::FounderListNotFound)
+        /// Applies `amount` to `founder`, capped at their promise unless the tribe raises toward a shared `FundingTarget`.
+        /// Returns the total funded and the excess that must be sent back
+        fn credit_funding(&self, founder: &mut Founder, amount: u128) -> Result<(u128, u128), TribeError> {
+            match self.config.activation_policy {
+                ActivationPolicy::FundingTarget { .. } => Ok((founder.contribute(amount)?, 0)),
+                _ => founder.fund(amount)
             }
         }
 
-        fn general_tribe_check(&self) -> Result<(), TribeError> {
-            if self.defunct {
-                return Err(TribeError::TribeIsDefunct);
+        /// Evaluates the `ActivationPolicy` against the founder tallies
+        fn activation_threshold_met(&self) -> bool {
+            match self.config.activation_policy {
+                ActivationPolicy::AllSettled => self.pending_founder_count == 0,
+                ActivationPolicy::RequiredFundedPlusOptional(optional) =>
+                    self.required_unfunded_count == 0 && self.optional_funded_count >= optional,
+                ActivationPolicy::MinimumTotalFunded(minimum) => self.committed_funded >= minimum,
+                ActivationPolicy::PercentageFunded(percent) =>
+                    self.committed_promised > 0
+                        && self.committed_funded.saturating_mul(100) >= self.committed_promised.saturating_mul(percent as u128),
+                ActivationPolicy::FundingTarget { soft_cap, .. } => self.committed_funded >= soft_cap,
+            }
+        }
+
+        fn get_founder(&self, founder_id: AccountId) -> Result<Founder, TribeError> {
+            match self.founders.get(founder_id) {
+                Some(founder) => Ok(founder),
+                None => Err(TribeError::NotAFounder)
+            }
+        }
+
+        /// Loads every founder in invitation order. Only used where the whole roster has to be settled
+        fn get_founder_list(&self) -> Result<Vec<Founder>, TribeError> {
+            let mut founders = Vec::new();
+            for index in 0..self.founder_count {
+                match self.founder_ids.get(index) {
+                    Some(founder_id) => founders.push(self.get_founder(founder_id)?),
+                    None => return Err(TribeError::FounderListNotFound)
+                }
+            }
+            Ok(founders)
+        }
+
+        /// Stores the `founder` record, appending new founders to `founder_ids` and keeping the tallies in step
+        fn save_founder(&mut self, founder: &Founder) {
+            match self.founders.get(founder.id) {
+                Some(previous) => self.untrack_founder(&previous),
+                None => {
+                    self.founder_ids.insert(self.founder_count, &founder.id);
+                    self.founder_count += 1;
+                    self.founder_seats.insert(founder.id, &self.seat_count);
+                    self.seat_count += 1;
+                }
+            }
+
+            self.track_founder(founder);
+            self.founders.insert(founder.id, founder);
+        }
+
+        /// Drops the `founder` record and closes the gap it leaves in `founder_ids`
+        fn remove_founder(&mut self, founder: &Founder) -> Result<(), TribeError> {
+            let mut position = None;
+            for index in 0..self.founder_count {
+                if self.founder_ids.get(index) == Some(founder.id) {
+                    position = Some(index);
+                    break;
+                }
+            }
+            let position = position.ok_or(TribeError::NotAFounder)?;
+
+            for index in position + 1..self.founder_count {
+                let founder_id = self.founder_ids.get(index).ok_or(TribeError::FounderListNotFound)?;
+                self.founder_ids.insert(index - 1, &founder_id);
             }
-            if self.enabled {
-                return Err(TribeError::ActiveTribeCannotAcceptFounderAction);
+            self.founder_count -= 1;
+            self.founder_ids.remove(self.founder_count);
+
+            // a revoked replacement frees the rejected seat it was invited for
+            let seat = self.seat_of(founder.id)?;
+            if let Some(replaced_seat) = self.replaced_seats.get(seat) {
+                self.replaced_seats.remove(seat);
+                self.replacements.remove(replaced_seat);
             }
+
+            self.untrack_founder(founder);
+            self.founders.remove(founder.id);
+            self.founder_seats.remove(founder.id);
+
             Ok(())
         }
 
-        fn get_founder_index(&self, founder_id: AccountId) -> Result<usize, TribeError> {
-            for (index, founder) in (self.get_founder_list()?).into_iter().enumerate() {
-                if founder_id == founder.id {
-                    return Ok(index);
+        /// Returns the seat of `founder`, votes are recorded against seats so a transferred seat can not vote twice
+        fn seat_of(&self, founder: AccountId) -> Result<u32, TribeError> {
+            self.founder_seats.get(founder).ok_or(TribeError::NotAFounder)
+        }
+
+        fn track_founder(&mut self, founder: &Founder) {
+            if founder.has_pending_activity() {
+                self.pending_founder_count += 1;
+            }
+            self.total_funded += founder.amount_funded();
+            self.forfeited_funds += founder.amount_forfeited();
+            self.refundable_funds += founder.amount_refundable();
+
+            if founder.is_accepted() {
+                self.accepted_founder_count += 1;
+            }
+            if founder.is_rejected() {
+                self.rejected_founder_count += 1;
+            } else {
+                if founder.required && !founder.is_funded() {
+                    self.required_unfunded_count += 1;
+                }
+                if !founder.required && founder.is_funded() {
+                    self.optional_funded_count += 1;
+                }
+                self.committed_promised += founder.amount_promised;
+                self.committed_funded += founder.amount_funded();
+            }
+        }
+
+        fn untrack_founder(&mut self, founder: &Founder) {
+            if founder.has_pending_activity() {
+                self.pending_founder_count -= 1;
+            }
+            self.total_funded -= founder.amount_funded();
+            self.forfeited_funds -= founder.amount_forfeited();
+            self.refundable_funds -= founder.amount_refundable();
+
+            if founder.is_accepted() {
+                self.accepted_founder_count -= 1;
+            }
+            if founder.is_rejected() {
+                self.rejected_founder_count -= 1;
+            } else {
+                if founder.required && !founder.is_funded() {
+                    self.required_unfunded_count -= 1;
                 }
+                if !founder.required && founder.is_funded() {
+                    self.optional_funded_count -= 1;
+                }
+                self.committed_promised -= founder.amount_promised;
+                self.committed_funded -= founder.amount_funded();
+            }
+        }
+
+        fn general_tribe_check(&self) -> Result<(), TribeError> {
+            self.status.ensure_forming()?;
+            if self.env().block_number() > self.founding_deadline {
+                return Err(TribeError::FoundingDeadlinePassed);
             }
-            Err(TribeError::NotAFounder)
+            Ok(())
         }
 
-        /// Mark the verified founder with a vote action of FOUNDER_ACCEPTED
+        /// Mark the verified founder with a vote action of `VoteAction::Accepted`. Activates the tribe if funds received before accepting already meet the threshold
         #[ink(message)]
         pub fn accept_tribe(&mut self) -> Result<(), TribeError> {
             self.general_tribe_check()?;
 
             let caller = self.env().caller();
-            let mut founders = self.get_founder_list()?;
-
-            let founder_index: usize = match self.get_founder_index(caller) {
-                Ok(index) => index,
-                Err(err) => return Err(err)
-            };
+            let mut founder = self.get_founder(caller)?;
+            founder.accept(self.env().block_number())?;
+            self.save_founder(&founder);
 
-            let founder = &founders[founder_index];
-            if founder.is_rejected() {
-                return Err(TribeError::FounderRejectedInvitation);
-            }
+            self.env().emit_event(FounderAccepted { founder: caller });
 
-            // we got this far, set action to ACCEPTED
-            founders[founder_index].vote_action = FOUNDER_ACCEPTED;
-            self.founders.insert(0, &founders);
+            self.activate_tribe()?;
 
             Ok(())
         }
 
-        /// Can record multiple funding actions for the verified founder. Only available to founders who have already `accept_tribe`
+        /// Can record multiple funding actions for the verified founder. Only available to founders who have already `accept_tribe`.
+        /// Value above the outstanding promise is sent straight back; returns the total funded and the amount refunded
         #[ink(message, payable, selector = 0xC4577B10)]
-        pub fn fund_tribe(&mut self) -> Result<u128, TribeError> {
+        pub fn fund_tribe(&mut self) -> Result<(u128, u128), TribeError> {
             self.general_tribe_check()?;
 
             let caller = self.env().caller();
@@ -126,16 +753,51 @@ mod tribe {
                 caller
             );
 
-            let mut founders = self.get_founder_list()?;
-            let founder_index = self.get_founder_index(caller)?;
-            let total_funded_amount = founders[founder_index].fund(value)?;
+            let mut founder = self.get_founder(caller)?;
+            let (total_funded_amount, excess) = self.credit_funding(&mut founder, value)?;
 
-            self.founders.insert(0, &founders);
+            if excess > 0 && self.env().transfer(caller, excess).is_err() {
+                return Err(TribeError::TransferFailed);
+            }
+
+            self.save_founder(&founder);
+
+            self.env().emit_event(FounderFunded {
+                founder: caller,
+                amount: value - excess,
+                total_funded: total_funded_amount,
+            });
+
+            if self.config.freeze_roster_on_funding && !founder.initial {
+                self.set_roster_frozen();
+            }
 
             self.activate_tribe()?;
 
-            // calculate differences, send difference back to each founder
-            // Dont implement this yet.
+            Ok((total_funded_amount, excess))
+        }
+
+        /// Sends `amount` of the verified founder's contribution back to them while the tribe is still forming. Withdrawing is subject to the
+        /// `CommitmentRule` like a rejection, otherwise a founder could empty their funds and then reject freely. Returns the founder's remaining total funded
+        #[ink(message)]
+        pub fn withdraw_contribution(&mut self, amount: u128) -> Result<u128, TribeError> {
+            self.general_tribe_check()?;
+            self.config.commitment_rule.check_rejection(self.env().block_number())?;
+
+            let caller = self.env().caller();
+            let mut founder = self.get_founder(caller)?;
+            let total_funded_amount = founder.withdraw(amount)?;
+
+            if self.env().transfer(caller, amount).is_err() {
+                return Err(TribeError::TransferFailed);
+            }
+            self.save_founder(&founder);
+
+            self.env().emit_event(FounderWithdrew {
+                founder: caller,
+                amount,
+                total_funded: total_funded_amount,
+            });
 
             Ok(total_funded_amount)
         }
@@ -143,725 +805,4810 @@ mod tribe {
         /// Returns current state of the founder as json
         #[ink(message)]
         pub fn get_founder_status(&self, founder: AccountId) -> Result<String, TribeError> {
-            let founder_index = self.get_founder_index(founder)?;
-            let founders = self.get_founder_list()?;
-            Ok(founders[founder_index].describe())
+            Ok(self.get_founder(founder)?.describe())
+        }
+
+        /// Returns current state of the founder
+        #[ink(message)]
+        pub fn get_founder_info(&self, founder: AccountId) -> Result<FounderInfo, TribeError> {
+            Ok(self.get_founder(founder)?.info())
         }
 
         /// Returns current state of tribe as json
         #[ink(message)]
         pub fn get_tribe(&self) -> String {
+            self.get_tribe_info().to_json()
+        }
+
+        /// Returns the tribe's name, status, deadline, roster size and total funded
+        #[ink(message)]
+        pub fn get_tribe_info(&self) -> TribeInfo {
+            TribeInfo {
+                name: self.name.clone(),
+                status: self.status,
+                founding_deadline: self.founding_deadline,
+                founder_count: self.founder_count,
+                total_funded: self.total_funded,
+                roster_frozen: self.roster_frozen,
+            }
+        }
+
+        /// Returns the rules the tribe was created with
+        #[ink(message)]
+        pub fn get_tribe_config(&self) -> TribeConfig {
+            self.config
+        }
+
+        /// Returns the current `TribeStatus`
+        #[ink(message)]
+        pub fn get_tribe_status(&self) -> TribeStatus {
+            self.status
+        }
 
-            ink_prelude::format!(r#"{{
-    "name": {},
-    "enabled": {},
-    "defunct": {}
-}}"#, 
-                &self.name,
-                &self.enabled,
-                &self.defunct
-            )
+        /// Returns the vote action taken by `founder`
+        #[ink(message)]
+        pub fn get_founder_vote(&self, founder: AccountId) -> Result<VoteAction, TribeError> {
+            Ok(self.get_founder(founder)?.vote_action())
         }
 
-        /// Attempt to include the `potential_founder` AccountId in the tribe contract’s founders collection. The initial founder must also provide the `amount_in_pico` to tribe and a flag to determine if this is a `required` founder
+        /// Attempt to include the `potential_founder` AccountId in the tribe contract’s founders collection. The inviter must also provide the `amount_in_pico` to tribe and a flag to determine if this is a `required` founder. Requires the `Inviter` role
         #[ink(message)]
         pub fn invite_founder(&mut self, potential_founder: AccountId, amount_in_pico: u128, required: bool) -> Result<(), TribeError> {
-            self.general_tribe_check()?;
+            self.check_invitation(potential_founder)?;
 
-            let caller = Self::env().caller();
-            if caller == potential_founder {
-                return Err(TribeError::CanNotInviteInitialFounder);
+            let new_founder = Founder::new(potential_founder, required, amount_in_pico)?;
+            self.add_invited_founder(&new_founder);
+
+            Ok(())
+        }
+
+        /// Same as `invite_founder`, but the `potential_founder` promises the sum of `installments` and pays each one by its `due_block`.
+        /// Every `due_block` must be in increasing order and no later than the `founding_deadline`
+        #[ink(message)]
+        pub fn invite_founder_with_installments(&mut self, potential_founder: AccountId, installments: Vec<Installment>, required: bool) -> Result<(), TribeError> {
+            self.check_invitation(potential_founder)?;
+
+            if installments.iter().any(|installment| installment.due_block > self.founding_deadline) {
+                return Err(TribeError::InvalidInstallmentPlan);
             }
 
-            let mut founders = self.get_founder_list()?;
+            let new_founder = Founder::with_installments(potential_founder, required, installments)?;
+            self.add_invited_founder(&new_founder);
 
-            let founder_index = self.get_founder_index(caller)?;
-            let initial_founder = &founders[founder_index];
+            Ok(())
+        }
 
-            // is the caller the initial_founder?
-            if !initial_founder.initial {
-                return Err(TribeError::NotInitialFounder);
+        /// Rules shared by every invitation sent by the initial founder
+        fn check_invitation(&self, potential_founder: AccountId) -> Result<(), TribeError> {
+            self.check_new_founder(potential_founder)?;
+
+            if self.roster_frozen {
+                return Err(TribeError::TribeIsLocked);
             }
 
-            // iterate all AccountIds
-            for founder in &founders {
-                // is founder already in the founder list?
-                if founder.id == potential_founder {
-                    return Err(TribeError::FounderAlreadyInvited);
-                }
+            Ok(())
+        }
 
-                // has any founder rejected? any amount funded?
-                if !founder.has_pending_activity() || founder.has_funds() {
-                    return Err(TribeError::TribeIsLocked);
-                }
+        /// Rules for adding `potential_founder` to the roster, frozen or not
+        fn check_new_founder(&self, potential_founder: AccountId) -> Result<(), TribeError> {
+            self.general_tribe_check()?;
+
+            let caller = Self::env().caller();
+            if caller == potential_founder {
+                return Err(TribeError::CanNotInviteInitialFounder);
             }
 
-            // we got this far, add the founder.
-            let new_founder = Founder::new(potential_founder, required, amount_in_pico)?;
-            founders.push(new_founder);
-            self.founders.insert(0, &founders);
+            self.ensure_role(caller, Role::Inviter)?;
+
+            // is founder already in the founder list?
+            if self.founders.contains(potential_founder) {
+                return Err(TribeError::FounderAlreadyInvited);
+            }
 
             Ok(())
         }
 
-        /// Attempts to mark the verified founder with a vote action of FOUNDER_REJECTED
+        fn add_invited_founder(&mut self, new_founder: &Founder) {
+            self.save_founder(new_founder);
+
+            self.env().emit_event(FounderInvited {
+                founder: new_founder.id,
+                amount_promised: new_founder.amount_promised,
+                required: new_founder.required,
+            });
+        }
+
+        /// Proposes a new `amount_in_pico` promised by `founder`, which only applies once that founder calls `confirm_amount_promised`. Founders can not propose their own amount. Requires the `Inviter` role
         #[ink(message)]
-        pub fn reject_tribe(&mut self) -> Result<(), TribeError> {
+        pub fn propose_amount_promised(&mut self, founder: AccountId, amount_in_pico: u128) -> Result<(), TribeError> {
             self.general_tribe_check()?;
 
             let caller = self.env().caller();
+            self.ensure_role(caller, Role::Inviter)?;
 
-            // if founder does NOT exist in founders_required, fail
-            let mut founders = self.get_founder_list()?;
-            let founder_index = self.get_founder_index(caller)?;
-            founders[founder_index].vote_action = FOUNDER_REJECTED;
-
-            if founders[founder_index].required {
-                self.defunct = true;
-
-                // TODO REFUND EVERYONE WHO SENT UNITS
-            } else {
-                self.activate_tribe()?;
+            // the founder confirms the amount, so someone else has to propose it
+            if caller == founder {
+                return Err(TribeError::CanNotProposeOwnAmount);
             }
 
-            self.founders.insert(0, &founders);
-            // if any founder has already funded tribe, return funds to each founder
-            // TODO
+            let mut proposed_founder = self.get_founder(founder)?;
+            proposed_founder.propose_amount(amount_in_pico)?;
+            self.save_founder(&proposed_founder);
+
+            self.env().emit_event(AmountPromisedProposed {
+                founder,
+                amount_promised: amount_in_pico,
+            });
 
             Ok(())
         }
-    }
-
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use ink_lang as ink;
-        use crate::errors::MyDisplay;
 
-        const NAME: &str = "a test tribe";
+        /// Accepts the `amount_promised` proposed for the verified founder. Funding above the new promise is sent back; returns the amount refunded
+        #[ink(message)]
+        pub fn confirm_amount_promised(&mut self) -> Result<u128, TribeError> {
+            self.general_tribe_check()?;
 
-//******************************** create_tribe  ********************************
-        #[ink::test]
-        fn create_tribe_success() {
-            //ACT
-            let tribe = TribeContract::new(NAME.to_string(), 5000);
+            let caller = self.env().caller();
+            let mut founder = self.get_founder(caller)?;
+            let excess = founder.confirm_amount()?;
 
-            //ASSERT
-            assert_eq!(tribe.name, NAME.to_string());
-            assert!(!tribe.enabled);
-            assert!(!tribe.defunct);
-        }
+            if excess > 0 && self.env().transfer(caller, excess).is_err() {
+                return Err(TribeError::TransferFailed);
+            }
+            self.save_founder(&founder);
 
-        #[ink::test]
-        fn create_tribe_contains_only_initial_founder() {
-            //ASSIGN
-            let tribe = TribeContract::new(NAME.to_string(), 5000);
+            self.env().emit_event(AmountPromisedConfirmed {
+                founder: caller,
+                amount_promised: founder.amount_promised,
+                refunded: excess,
+            });
 
-            //ACT
-            assert!(tribe.founders.contains(0));
-            let founder_vec = tribe.founders.get(0).expect("expected vector of founders");
+            self.activate_tribe()?;
 
-            //ASSERT
-            assert_eq!(tribe.name, NAME.to_string());
-            assert_eq!(founder_vec.len(), 1);
-            assert!(founder_vec[0].initial);  //assert only member of newly started tribe is the initial founder
+            Ok(excess)
         }
 
-//******************************** activate_tribe  ********************************
-        #[ink::test]
-        fn activate_tribe_with_no_activity_should_have_no_effect() {
+        /// Removes the `founder` from the tribe contract’s founders collection as long as they have not answered the invitation. Requires the `Inviter` role
+        #[ink(message)]
+        pub fn revoke_invitation(&mut self, founder: AccountId) -> Result<(), TribeError> {
+            self.general_tribe_check()?;
+
+            let caller = self.env().caller();
+            self.ensure_role(caller, Role::Inviter)?;
+
+            let revoked_founder = self.get_founder(founder)?;
+            if revoked_founder.initial {
+                return Err(TribeError::CanNotRevokeInitialFounder);
+            }
+            if revoked_founder.has_funds() {
+                return Err(TribeError::FounderHasFunds);
+            }
+            if revoked_founder.vote_action() != VoteAction::Pending {
+                return Err(TribeError::FounderNotPending);
+            }
+
+            self.remove_founder(&revoked_founder)?;
+
+            self.env().emit_event(FounderRevoked { founder });
+
+            // the revoked founder may have been the only one holding up activation
+            self.activate_tribe()
+        }
+
+        /// Attempts to mark the verified founder with a vote action of `VoteAction::Rejected`. A founder who has funded is subject to the `CommitmentRule`
+        #[ink(message)]
+        pub fn reject_tribe(&mut self) -> Result<(), TribeError> {
+            self.general_tribe_check()?;
+
+            let caller = self.env().caller();
+
+            // if founder does NOT exist in founders_required, fail
+            let mut founder = self.get_founder(caller)?;
+            founder.reject()?;
+
+            if founder.has_funds() {
+                self.config.commitment_rule.check_rejection(self.env().block_number())?;
+            }
+
+            self.settle_rejection(&founder)
+        }
+
+        /// Rejects the tribe for the verified founder under a `CommitmentRule::Penalty`. The penalty share of their funds is redistributed
+        /// pro rata to the other founders who have funded and not rejected, who can `claim_refund` it. Returns the penalty paid
+        #[ink(message)]
+        pub fn reject_tribe_with_penalty(&mut self) -> Result<u128, TribeError> {
+            self.general_tribe_check()?;
+
+            let percent = match self.config.commitment_rule {
+                CommitmentRule::Penalty(percent) => percent,
+                _ => return Err(TribeError::NoRejectionPenalty)
+            };
+
+            let caller = self.env().caller();
+            let mut founder = self.get_founder(caller)?;
+            founder.reject()?;
+
+            let penalty = self.charge_rejection_penalty(&mut founder, percent)?;
+
+            self.settle_rejection(&founder)?;
+
+            Ok(penalty)
+        }
+
+        /// Takes `percent` of the funds of `founder`, who is leaving the tribe, and redistributes it with `redistribute_penalty`. Returns the penalty paid
+        fn charge_rejection_penalty(&mut self, founder: &mut Founder, percent: u8) -> Result<u128, TribeError> {
+            let others_funded = self.committed_funded - founder.amount_funded();
+            let penalty = mul_div(founder.amount_funded(), percent as u128, 100).ok_or(TribeError::ArithmeticOverflow)?;
+            let penalty = self.redistribute_penalty(founder.id, penalty, others_funded)?;
+            if penalty > 0 {
+                founder.withdraw(penalty)?;
+            }
+            self.env().emit_event(RejectionPenalized { founder: founder.id, penalty });
+
+            Ok(penalty)
+        }
+
+        /// Credits `penalty` pro rata to the founders other than `payer` who have funded and not rejected, `others_funded` being their total.
+        /// Rounding leaves the remainder with the `payer`. Returns the amount credited
+        fn redistribute_penalty(&mut self, payer: AccountId, penalty: u128, others_funded: u128) -> Result<u128, TribeError> {
+            if penalty == 0 || others_funded == 0 {
+                return Ok(0);
+            }
+
+            let mut credited = 0;
+            for mut recipient in self.get_founder_list()? {
+                if recipient.id == payer || recipient.is_rejected() || !recipient.has_funds() {
+                    continue;
+                }
+                let share = mul_div(penalty, recipient.amount_funded(), others_funded).ok_or(TribeError::ArithmeticOverflow)?;
+                if share > 0 {
+                    recipient.credit_refundable(share);
+                    self.save_founder(&recipient);
+                    credited += share;
+                }
+            }
+
+            Ok(credited)
+        }
+
+        /// Treats the first overdue installment of `founder` like a rejection of the tribe by that founder. Anyone may call this.
+        /// Missing a payment is no way around the `CommitmentRule`: where the founder could not reject, they pay the rejection penalty
+        /// under `CommitmentRule::Penalty` and forfeit their funds otherwise
+        #[ink(message)]
+        pub fn enforce_installments(&mut self, founder: AccountId) -> Result<(), TribeError> {
+            self.general_tribe_check()?;
+
+            let mut missed_founder = self.get_founder(founder)?;
+            let installment = *missed_founder.overdue_installments(self.env().block_number())
+                .first()
+                .ok_or(TribeError::NoInstallmentOverdue)?;
+            missed_founder.reject()?;
+
+            self.env().emit_event(InstallmentMissed { founder, installment });
+
+            if missed_founder.has_funds() && self.config.commitment_rule.check_rejection(self.env().block_number()).is_err() {
+                match self.config.commitment_rule {
+                    CommitmentRule::Penalty(percent) => {
+                        self.charge_rejection_penalty(&mut missed_founder, percent)?;
+                    },
+                    _ => {
+                        missed_founder.forfeit();
+                    }
+                }
+            }
+
+            self.settle_rejection(&missed_founder)
+        }
+
+        /// Invites `potential_founder` in place of `rejected_founder`, an optional founder who rejected the tribe or was ejected,
+        /// with the same `amount_promised`. Works even once the roster is frozen, each rejected founder can be replaced once unless the replacement's invitation is revoked.
+        /// Requires the `Inviter` role
+        #[ink(message)]
+        pub fn invite_replacement(&mut self, rejected_founder: AccountId, potential_founder: AccountId) -> Result<(), TribeError> {
+            self.check_new_founder(potential_founder)?;
+
+            let replaced = self.get_founder(rejected_founder)?;
+            if !replaced.is_rejected() {
+                return Err(TribeError::FounderNotRejected);
+            }
+            if replaced.required {
+                return Err(TribeError::CanNotReplaceRequiredFounder);
+            }
+            // keyed by seat so moving the rejected seat to another account does not allow a second replacement
+            let replaced_seat = self.seat_of(rejected_founder)?;
+            if self.replacements.contains(replaced_seat) {
+                return Err(TribeError::FounderAlreadyReplaced);
+            }
+
+            let new_founder = Founder::new(potential_founder, false, replaced.amount_promised)?;
+            self.add_invited_founder(&new_founder);
+            let seat = self.seat_of(potential_founder)?;
+            self.replacements.insert(replaced_seat, &seat);
+            self.replaced_seats.insert(seat, &replaced_seat);
+
+            self.env().emit_event(FounderReplaced {
+                rejected_founder,
+                founder: potential_founder,
+            });
+
+            Ok(())
+        }
+
+        /// Stops any further invitation, only replacements for rejected optional founders are still possible.
+        /// Requires the `Inviter` role
+        #[ink(message)]
+        pub fn freeze_roster(&mut self) -> Result<(), TribeError> {
+            self.general_tribe_check()?;
+
+            let caller = self.env().caller();
+            self.ensure_role(caller, Role::Inviter)?;
+
+            self.set_roster_frozen();
+
+            Ok(())
+        }
+
+        fn set_roster_frozen(&mut self) {
+            if !self.roster_frozen {
+                self.roster_frozen = true;
+                self.env().emit_event(RosterFrozen {});
+            }
+        }
+
+        /// Proposes `nominee`, an existing founder who has not rejected, to take over the initial founder role.
+        /// The handover completes once `nominee` calls `accept_initial_founder`, proposing again replaces the nominee.
+        /// Only available to the initial founder
+        #[ink(message)]
+        pub fn propose_initial_founder(&mut self, nominee: AccountId) -> Result<(), TribeError> {
+            let caller = self.env().caller();
+            if !self.get_founder(caller)?.initial {
+                return Err(TribeError::NotInitialFounder);
+            }
+
+            let nominee_founder = self.get_founder(nominee)?;
+            if nominee_founder.initial {
+                return Err(TribeError::AlreadyInitialFounder);
+            }
+            if nominee_founder.is_rejected() {
+                return Err(TribeError::FounderRejectedInvitation);
+            }
+
+            self.initial_founder_nominee = Some(nominee);
+            self.env().emit_event(InitialFounderProposed {
+                founder: caller,
+                nominee,
+            });
+
+            Ok(())
+        }
+
+        /// Takes over the initial founder role for the verified founder, who was proposed through `propose_initial_founder`
+        #[ink(message)]
+        pub fn accept_initial_founder(&mut self) -> Result<(), TribeError> {
+            let caller = self.env().caller();
+            if self.initial_founder_nominee != Some(caller) {
+                return Err(TribeError::NoInitialFounderHandover);
+            }
+
+            let mut founder = self.get_founder(caller)?;
+            if founder.is_rejected() {
+                return Err(TribeError::FounderRejectedInvitation);
+            }
+
+            // the initial founder may have transferred their seat since proposing
+            let mut previous = self.get_initial_founder()?;
+            previous.initial = false;
+            founder.initial = true;
+            self.save_founder(&previous);
+            self.save_founder(&founder);
+            self.initial_founder_nominee = None;
+
+            self.env().emit_event(InitialFounderChanged {
+                previous: previous.id,
+                founder: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the founder whose seat holds the initial founder role
+        fn get_initial_founder(&self) -> Result<Founder, TribeError> {
+            self.get_founder_list()?
+                .into_iter()
+                .find(|founder| founder.initial)
+                .ok_or(TribeError::NotInitialFounder)
+        }
+
+        /// Proposes `new_account` to take over the verified founder's seat, with its vote, promise, funds and flags.
+        /// The transfer completes once `new_account` calls `accept_founder_seat`, neither is possible while the seat's shares back an open vote
+        #[ink(message)]
+        pub fn transfer_founder_seat(&mut self, new_account: AccountId) -> Result<(), TribeError> {
+            let caller = self.env().caller();
+            let mut founder = self.get_founder(caller)?;
+
+            if self.founders.contains(new_account) {
+                return Err(TribeError::FounderAlreadyInvited);
+            }
+            if self.shares_locked(caller) {
+                return Err(TribeError::SeatSharesLocked);
+            }
+
+            founder.propose_seat_transfer(new_account);
+            self.save_founder(&founder);
+
+            self.env().emit_event(FounderSeatTransferProposed {
+                founder: caller,
+                new_account,
+            });
+
+            Ok(())
+        }
+
+        /// Takes over the seat of `founder`, who proposed the verified account through `transfer_founder_seat`
+        #[ink(message)]
+        pub fn accept_founder_seat(&mut self, founder: AccountId) -> Result<(), TribeError> {
+            let caller = self.env().caller();
+            let previous = self.get_founder(founder)?;
+
+            // the account may have joined the roster since the transfer was proposed
+            if self.founders.contains(caller) {
+                return Err(TribeError::FounderAlreadyInvited);
+            }
+            // the shares that carried an open vote must not reach an account that could vote with them again
+            if self.shares_locked(founder) {
+                return Err(TribeError::SeatSharesLocked);
+            }
+
+            let mut seat = self.get_founder(founder)?;
+            seat.complete_seat_transfer(caller)?;
+            self.move_founder(&previous, &seat)?;
+
+            self.env().emit_event(FounderSeatTransferred {
+                from: founder,
+                to: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Replaces the `previous` record with `founder`, stored under its new id at the same position in `founder_ids`
+        fn move_founder(&mut self, previous: &Founder, founder: &Founder) -> Result<(), TribeError> {
+            let mut position = None;
+            for index in 0..self.founder_count {
+                if self.founder_ids.get(index) == Some(previous.id) {
+                    position = Some(index);
+                    break;
+                }
+            }
+            let position = position.ok_or(TribeError::NotAFounder)?;
+
+            let seat = self.seat_of(previous.id)?;
+
+            self.untrack_founder(previous);
+            self.founders.remove(previous.id);
+            self.founder_seats.remove(previous.id);
+            self.founder_seats.insert(founder.id, &seat);
+            for role in Role::ALL {
+                if self.roles.contains((previous.id, role)) {
+                    self.roles.remove((previous.id, role));
+                    self.roles.insert((founder.id, role), &true);
+                }
+            }
+            self.share_locks.remove(previous.id);
+            let shares = self.balance_of(previous.id);
+            if shares > 0 {
+                self.share_balances.remove(previous.id);
+                self.share_balances.insert(founder.id, &(self.balance_of(founder.id) + shares));
+                self.env().emit_event(Transfer {
+                    from: Some(previous.id),
+                    to: Some(founder.id),
+                    value: shares,
+                });
+            }
+
+            self.founder_ids.insert(position, &founder.id);
+            self.track_founder(founder);
+            self.founders.insert(founder.id, founder);
+
+            Ok(())
+        }
+
+        /// Casts the verified founder's vote to eject `founder`, who accepted and, once the `funding_grace_period` has passed, has missed an installment
+        /// or has not fully funded when not paying by installments.
+        /// Once more than half of the other founders who have not rejected vote, an optional founder is ejected and a required founder
+        /// makes the tribe defunct. Returns `true` when this vote ejected the founder
+        #[ink(message)]
+        pub fn vote_to_eject(&mut self, founder: AccountId) -> Result<bool, TribeError> {
+            self.general_tribe_check()?;
+            let grace_period = self.config.funding_grace_period.ok_or(TribeError::EjectionDisabled)?;
+
+            let caller = self.env().caller();
+            if caller == founder {
+                return Err(TribeError::CanNotVoteToEjectSelf);
+            }
+            if self.get_founder(caller)?.is_rejected() {
+                return Err(TribeError::FounderRejectedInvitation);
+            }
+
+            let mut laggard = self.get_founder(founder)?;
+            if !laggard.is_lagging(self.env().block_number(), grace_period) {
+                return Err(TribeError::FounderNotLagging);
+            }
+            let (laggard_seat, voter_seat) = (self.seat_of(founder)?, self.seat_of(caller)?);
+            if self.eject_votes.contains((laggard_seat, voter_seat)) {
+                return Err(TribeError::EjectionVoteAlreadyCast);
+            }
+
+            let votes = self.eject_vote_count.get(laggard_seat).unwrap_or(0) + 1;
+            self.eject_votes.insert((laggard_seat, voter_seat), &true);
+            self.eject_vote_count.insert(laggard_seat, &votes);
+            self.env().emit_event(EjectVoteCast { voter: caller, founder, votes });
+
+            // the laggard has not rejected, so it is counted among the founders who have not
+            let voters = self.founder_count - self.rejected_founder_count - 1;
+            if votes * 2 <= voters {
+                return Ok(false);
+            }
+
+            laggard.reject()?;
+            // a defunct tribe gives everything back, so only an optional founder can forfeit
+            let forfeited = if !laggard.required && self.config.laggard_funds == LaggardFunds::Forfeited {
+                laggard.forfeit()
+            } else {
+                0
+            };
+            self.env().emit_event(FounderEjected { founder, forfeited });
+
+            self.settle_rejection(&laggard)?;
+
+            Ok(true)
+        }
+
+        /// Returns the number of votes cast to eject `founder`
+        #[ink(message)]
+        pub fn get_eject_votes(&self, founder: AccountId) -> u32 {
+            self.founder_seats.get(founder)
+                .and_then(|seat| self.eject_vote_count.get(seat))
+                .unwrap_or(0)
+        }
+
+        /// Returns the indexes of the `founder`'s unpaid installments that are past their `due_block`
+        #[ink(message)]
+        pub fn get_overdue_installments(&self, founder: AccountId) -> Result<Vec<u32>, TribeError> {
+            Ok(self.get_founder(founder)?.overdue_installments(self.env().block_number()))
+        }
+
+        /// Stores a `founder` who has just rejected, a required founder makes the tribe defunct
+        fn settle_rejection(&mut self, founder: &Founder) -> Result<(), TribeError> {
+            self.save_founder(founder);
+
+            let required = founder.required;
+            self.env().emit_event(FounderRejected { founder: founder.id, required });
+
+            // funded founders recover their units through `claim_refund`
+            if required {
+                self.status.transition(TribeStatus::Defunct)?;
+                self.env().emit_event(TribeDefunct { founder: Some(founder.id) });
+            } else {
+                self.activate_tribe()?;
+            }
+
+            Ok(())
+        }
+
+        /// Marks a tribe that did not activate by its `founding_deadline` as defunct and refunds every funded founder. A tribe that met its
+        /// activation threshold without a founder action to activate it, such as one funded by the endowment alone, is activated instead. Anyone may call this
+        #[ink(message)]
+        pub fn finalize_founding(&mut self) -> Result<(), TribeError> {
+            self.status.ensure_forming()?;
+            if self.env().block_number() <= self.founding_deadline {
+                return Err(TribeError::FoundingDeadlineNotReached);
+            }
+
+            // the constructor leaves activation to the next founder action, which may never come
+            if self.activation_threshold_met() {
+                return self.activate_tribe();
+            }
+
+            self.status.transition(TribeStatus::Defunct)?;
+            self.env().emit_event(TribeDefunct { founder: None });
+
+            // a founder whose transfer fails keeps their balance recorded and can still `claim_refund`
+            for mut founder in self.get_founder_list()? {
+                let amount = founder.refund() + founder.release_forfeited();
+                if amount > 0 && self.env().transfer(founder.id, amount).is_ok() {
+                    self.save_founder(&founder);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Sends the verified founder's `amount_funded` back to them. Only available once the tribe is defunct or the founder has rejected it,
+        /// except for a contribution scaled back at activation whose transfer failed or a share of a rejection penalty, which can be claimed at any time
+        #[ink(message)]
+        pub fn claim_refund(&mut self) -> Result<u128, TribeError> {
+            let caller = self.env().caller();
+            let mut founder = self.get_founder(caller)?;
+
+            // outside of a failed tribe or a rejection only a scaled back contribution can be claimed
+            let tribe_failed = self.status == TribeStatus::Defunct || self.status == TribeStatus::Dissolved;
+            let amount = if tribe_failed {
+                founder.refund() + founder.release_forfeited()
+            } else if founder.is_rejected() {
+                founder.refund()
+            } else if founder.amount_refundable() > 0 {
+                founder.release_refundable()
+            } else {
+                return Err(TribeError::RefundNotAvailable);
+            };
+            if amount == 0 {
+                return Err(TribeError::NoFundsToRefund);
+            }
+
+            // only record the refund once the transfer went through
+            if self.env().transfer(caller, amount).is_err() {
+                return Err(TribeError::TransferFailed);
+            }
+            self.save_founder(&founder);
+
+            Ok(amount)
+        }
+
+        /// Closes out a defunct tribe once every founder has been refunded. Anyone may call this
+        #[ink(message)]
+        pub fn dissolve_tribe(&mut self) -> Result<(), TribeError> {
+            if self.total_funded > 0 || self.forfeited_funds > 0 || self.refundable_funds > 0 {
+                return Err(TribeError::RefundsOutstanding);
+            }
+
+            self.status.transition(TribeStatus::Dissolved)?;
+            self.env().emit_event(TribeDissolved {});
+
+            Ok(())
+        }
+
+        /// Proposes to pay `amount` of the tribe's funds to `recipient`, `description_hash` identifies the description agreed off chain.
+        /// Only available to accepted founders and `Treasurer`s of an active tribe. Returns the id of the new proposal
+        #[ink(message)]
+        pub fn submit_spend_proposal(&mut self, recipient: AccountId, amount: u128, description_hash: Hash) -> Result<u32, TribeError> {
+            self.status.ensure_active()?;
+
+            let caller = self.env().caller();
+            if !self.has_role(caller, Role::Treasurer) {
+                self.get_voting_founder(caller)?;
+            }
+            if amount > self.get_treasury_balance() {
+                return Err(TribeError::InsufficientTreasuryFunds);
+            }
+
+            let id = self.spend_proposal_count;
+            let voter_count = self.accepted_founder_count;
+            let proposal = SpendProposal::new(id, caller, recipient, amount, description_hash, voter_count)?;
+            self.spend_proposals.insert(id, &proposal);
+            self.spend_proposal_count += 1;
+
+            self.env().emit_event(SpendProposed {
+                id,
+                proposer: caller,
+                recipient,
+                amount,
+            });
+
+            Ok(id)
+        }
+
+        /// Casts the verified founder's vote on spend proposal `id`. Once more than half of the founders who could vote
+        /// when it was submitted approve, the amount is transferred to the recipient. Returns the proposal's `SpendStatus`
+        #[ink(message)]
+        pub fn vote_on_spend_proposal(&mut self, id: u32, approve: bool) -> Result<SpendStatus, TribeError> {
+            self.status.ensure_active()?;
+
+            let caller = self.env().caller();
+            self.get_voting_founder(caller)?;
+            let seat = self.seat_of(caller)?;
+            let mut proposal = self.spend_proposals.get(id).ok_or(TribeError::SpendProposalNotFound)?;
+            if self.spend_votes.contains((id, seat)) {
+                return Err(TribeError::SpendVoteAlreadyCast);
+            }
+
+            proposal.vote(approve)?;
+            if proposal.is_approved() {
+                if proposal.amount > self.get_treasury_balance() {
+                    return Err(TribeError::InsufficientTreasuryFunds);
+                }
+                if self.env().transfer(proposal.recipient, proposal.amount).is_err() {
+                    return Err(TribeError::TransferFailed);
+                }
+                proposal.execute();
+                self.treasury_spent += proposal.amount;
+            }
+
+            self.spend_votes.insert((id, seat), &true);
+            self.spend_proposals.insert(id, &proposal);
+
+            self.env().emit_event(SpendVoteCast {
+                id,
+                voter: caller,
+                approve,
+            });
+            match proposal.status {
+                SpendStatus::Executed => self.env().emit_event(SpendExecuted {
+                    id,
+                    recipient: proposal.recipient,
+                    amount: proposal.amount,
+                }),
+                SpendStatus::Rejected => self.env().emit_event(SpendRejected { id }),
+                SpendStatus::Open => {}
+            }
+
+            Ok(proposal.status)
+        }
+
+        /// Returns spend proposal `id`, if it exists
+        #[ink(message)]
+        pub fn get_spend_proposal(&self, id: u32) -> Option<SpendProposal> {
+            self.spend_proposals.get(id)
+        }
+
+        /// Returns the tribe's funds that have not been paid out by spend proposals, including the funds forfeited by ejected founders.
+        /// Funds of founders who rejected are owed back to them
+        #[ink(message)]
+        pub fn get_treasury_balance(&self) -> u128 {
+            self.committed_funded + self.forfeited_funds - self.treasury_spent
+        }
+
+        /// Submits a governance proposal identified by `description_hash`, open for votes during the configured `voting_period`.
+        /// Only available to accepted founders of an active tribe. Returns the id of the new proposal
+        #[ink(message)]
+        pub fn submit_proposal(&mut self, description_hash: Hash) -> Result<u32, TribeError> {
+            self.status.ensure_active()?;
+
+            let caller = self.env().caller();
+            self.get_voting_founder(caller)?;
+
+            let id = self.proposal_count;
+            let end_block = self.env().block_number() + self.config.governance.voting_period;
+            let total_weight = self.get_founder_list()?
+                .iter()
+                .filter(|founder| founder.is_accepted())
+                .map(|founder| self.voting_weight(founder))
+                .sum();
+            let proposal = Proposal::new(id, caller, description_hash, end_block, total_weight);
+            self.proposals.insert(id, &proposal);
+            self.proposal_count += 1;
+
+            self.env().emit_event(ProposalSubmitted {
+                id,
+                proposer: caller,
+                end_block,
+            });
+
+            Ok(id)
+        }
+
+        /// Casts the verified founder's vote on governance proposal `id` with their voting weight. Returns the weight cast
+        #[ink(message)]
+        pub fn vote_on_proposal(&mut self, id: u32, approve: bool) -> Result<u128, TribeError> {
+            self.status.ensure_active()?;
+
+            let caller = self.env().caller();
+            let founder = self.get_voting_founder(caller)?;
+            let seat = self.seat_of(caller)?;
+            let mut proposal = self.proposals.get(id).ok_or(TribeError::ProposalNotFound)?;
+            if self.proposal_votes.contains((id, seat)) {
+                return Err(TribeError::ProposalVoteAlreadyCast);
+            }
+
+            let weight = self.voting_weight(&founder);
+            proposal.vote(self.env().block_number(), approve, weight)?;
+            if self.config.governance.weighting == VoteWeighting::ByShares
+                && self.share_locks.get(caller).unwrap_or_default() < proposal.end_block {
+                // the shares that carried this vote stay put until it ends so they can not vote again from another founder
+                self.share_locks.insert(caller, &proposal.end_block);
+            }
+            self.proposal_votes.insert((id, seat), &true);
+            self.proposals.insert(id, &proposal);
+
+            self.env().emit_event(ProposalVoteCast {
+                id,
+                voter: caller,
+                approve,
+                weight,
+            });
+
+            Ok(weight)
+        }
+
+        /// Decides governance proposal `id` once its voting period has ended. Anyone may call this
+        #[ink(message)]
+        pub fn close_proposal(&mut self, id: u32) -> Result<ProposalStatus, TribeError> {
+            let mut proposal = self.proposals.get(id).ok_or(TribeError::ProposalNotFound)?;
+            let status = proposal.close(self.env().block_number(), &self.config.governance)?;
+            self.proposals.insert(id, &proposal);
+
+            self.env().emit_event(ProposalClosed {
+                id,
+                status,
+            });
+
+            Ok(status)
+        }
+
+        /// Returns governance proposal `id`, if it exists
+        #[ink(message)]
+        pub fn get_proposal(&self, id: u32) -> Option<Proposal> {
+            self.proposals.get(id)
+        }
+
+        /// Asks for the verified account to join the active tribe as a member. Founders can not request membership
+        #[ink(message)]
+        pub fn request_membership(&mut self) -> Result<(), TribeError> {
+            self.status.ensure_active()?;
+
+            let caller = self.env().caller();
+            if self.founders.contains(caller) {
+                return Err(TribeError::FounderAlreadyInvited);
+            }
+            if let Some(member) = self.members.get(caller) {
+                if member.is_approved() {
+                    return Err(TribeError::MemberAlreadyApproved);
+                }
+                return Err(TribeError::MembershipAlreadyRequested);
+            }
+
+            let member = Member::new(caller, self.membership_request_count, self.env().block_number());
+            self.members.insert(caller, &member);
+            self.membership_request_count += 1;
+
+            self.env().emit_event(MembershipRequested {
+                member: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Approves the membership request of `member` on behalf of the verified account. An `Admin` approval
+        /// admits `member` straight away, otherwise `member` joins once more than half of the accepted founders approve.
+        /// Returns `true` when this approval admitted `member`
+        #[ink(message)]
+        pub fn approve_membership(&mut self, member: AccountId) -> Result<bool, TribeError> {
+            self.status.ensure_active()?;
+
+            let caller = self.env().caller();
+            let admin = self.has_role(caller, Role::Admin);
+            if !admin {
+                self.get_voting_founder(caller)?;
+            }
+            let mut requested = self.members.get(member).ok_or(TribeError::NotAMember)?;
+            // an admin without a seat admits the member outright, so there is no vote to record
+            let seat = self.founder_seats.get(caller);
+            if let Some(seat) = seat {
+                if self.membership_votes.contains((requested.request_id, seat)) {
+                    return Err(TribeError::MembershipVoteAlreadyCast);
+                }
+            }
+
+            let approvals = requested.add_approval()?;
+            if let Some(seat) = seat {
+                self.membership_votes.insert((requested.request_id, seat), &true);
+            }
+
+            self.env().emit_event(MembershipApprovalCast {
+                approver: caller,
+                member,
+                approvals,
+            });
+
+            let admitted = admin || approvals * 2 > self.accepted_founder_count;
+            if admitted {
+                requested.approve(self.env().block_number())?;
+                self.member_count += 1;
+                self.env().emit_event(MemberAdded {
+                    member,
+                });
+            }
+            self.members.insert(member, &requested);
+
+            Ok(admitted)
+        }
+
+        /// Removes `member` from the tribe, or drops their pending request. Available to `Admin` and to `member` themselves
+        #[ink(message)]
+        pub fn remove_member(&mut self, member: AccountId) -> Result<(), TribeError> {
+            let caller = self.env().caller();
+            if caller != member && !self.has_role(caller, Role::Admin) {
+                return Err(TribeError::NotAllowedToRemoveMember);
+            }
+
+            let removed = self.members.get(member).ok_or(TribeError::NotAMember)?;
+            if removed.is_approved() {
+                self.member_count -= 1;
+            }
+            self.members.remove(member);
+
+            self.env().emit_event(MemberRemoved {
+                member,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the membership record of `member`, including a pending request
+        #[ink(message)]
+        pub fn get_member(&self, member: AccountId) -> Option<Member> {
+            self.members.get(member)
+        }
+
+        /// Returns `true` when `account` is an approved member
+        #[ink(message)]
+        pub fn is_member(&self, account: AccountId) -> bool {
+            self.members.get(account).map(|member| member.is_approved()).unwrap_or(false)
+        }
+
+        /// Returns the number of approved members
+        #[ink(message)]
+        pub fn get_member_count(&self) -> u32 {
+            self.member_count
+        }
+
+        /// Grants `role` to `account`. Requires the `Admin` role
+        #[ink(message)]
+        pub fn grant_role(&mut self, account: AccountId, role: Role) -> Result<(), TribeError> {
+            let caller = self.env().caller();
+            self.ensure_role(caller, Role::Admin)?;
+            if self.roles.contains((account, role)) {
+                return Err(TribeError::RoleAlreadyGranted);
+            }
+
+            self.roles.insert((account, role), &true);
+            self.env().emit_event(RoleGranted {
+                account,
+                role,
+                admin: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Revokes `role` from `account`. The initial founder's roles are implied by the seat and permanent, they only pass on
+        /// with `accept_initial_founder`. Requires the `Admin` role
+        #[ink(message)]
+        pub fn revoke_role(&mut self, account: AccountId, role: Role) -> Result<(), TribeError> {
+            let caller = self.env().caller();
+            self.ensure_role(caller, Role::Admin)?;
+            if !self.roles.contains((account, role)) {
+                return Err(TribeError::RoleNotGranted);
+            }
+
+            self.roles.remove((account, role));
+            self.env().emit_event(RoleRevoked {
+                account,
+                role,
+                admin: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Returns `true` when `account` can act as `role`: the initial founder always holds every role,
+        /// any other account needs it from `grant_role`, where `Admin` implies every role
+        #[ink(message)]
+        pub fn has_role(&self, account: AccountId, role: Role) -> bool {
+            if self.founders.get(account).map(|founder| founder.initial).unwrap_or(false) {
+                return true;
+            }
+            Role::ALL.iter().any(|held| held.grants(role) && self.roles.contains((account, *held)))
+        }
+
+        fn ensure_role(&self, account: AccountId, role: Role) -> Result<(), TribeError> {
+            if !self.has_role(account, role) {
+                return Err(TribeError::MissingRole);
+            }
+            Ok(())
+        }
+
+        /// PSP22: returns the number of shares minted
+        #[ink(message, selector = 0x162df8c2)]
+        pub fn total_supply(&self) -> Balance {
+            self.share_supply
+        }
+
+        /// PSP22: returns the shares held by `owner`
+        #[ink(message, selector = 0x6568382f)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            self.share_balances.get(owner).unwrap_or_default()
+        }
+
+        /// PSP22: returns the shares `spender` may still move out of `owner`'s balance
+        #[ink(message, selector = 0x4d47d921)]
+        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.share_allowances.get((owner, spender)).unwrap_or_default()
+        }
+
+        /// PSP22: moves `value` of the verified account's shares to `to`
+        #[ink(message, selector = 0xdb20f9f5)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            let from = self.env().caller();
+            self.transfer_shares(from, to, value)
+        }
+
+        /// PSP22: moves `value` of `from`'s shares to `to` out of the verified account's allowance
+        #[ink(message, selector = 0x54b3c76e)]
+        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            let spender = self.env().caller();
+            let allowance = self.allowance(from, spender);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+
+            self.transfer_shares(from, to, value)?;
+            self.approve_shares(from, spender, allowance - value);
+
+            Ok(())
+        }
+
+        /// PSP22: allows `spender` to move up to `value` of the verified account's shares
+        #[ink(message, selector = 0xb20f1bbd)]
+        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            self.approve_shares(owner, spender, value);
+            Ok(())
+        }
+
+        /// PSP22: raises the allowance of `spender` over the verified account's shares by `delta_value`
+        #[ink(message, selector = 0x96d6b57a)]
+        pub fn increase_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            let allowance = self.allowance(owner, spender);
+            self.approve_shares(owner, spender, allowance.saturating_add(delta_value));
+            Ok(())
+        }
+
+        /// PSP22: lowers the allowance of `spender` over the verified account's shares by `delta_value`
+        #[ink(message, selector = 0xfecb57d5)]
+        pub fn decrease_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            let allowance = self.allowance(owner, spender);
+            if allowance < delta_value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+
+            self.approve_shares(owner, spender, allowance - delta_value);
+            Ok(())
+        }
+
+        /// PSP22Metadata: the share token is named after the tribe
+        #[ink(message, selector = 0x3d261bd4)]
+        pub fn token_name(&self) -> Option<String> {
+            Some(self.name.clone())
+        }
+
+        /// PSP22Metadata: the share token has no symbol
+        #[ink(message, selector = 0x34205be5)]
+        pub fn token_symbol(&self) -> Option<String> {
+            None
+        }
+
+        /// PSP22Metadata: one share is minted per pico funded, so shares use the same 12 decimals
+        #[ink(message, selector = 0x7271b782)]
+        pub fn token_decimals(&self) -> u8 {
+            12
+        }
+
+        /// Whether the shares of `account` back a vote on a proposal that is still open
+        fn shares_locked(&self, account: AccountId) -> bool {
+            self.share_locks.get(account).is_some_and(|until| until >= self.env().block_number())
+        }
+
+        fn transfer_shares(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            if value > 0 && self.shares_locked(from) {
+                return Err(PSP22Error::shares_locked());
+            }
+
+            self.share_balances.insert(from, &(from_balance - value));
+            self.share_balances.insert(to, &(self.balance_of(to) + value));
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+
+            Ok(())
+        }
+
+        fn approve_shares(&mut self, owner: AccountId, spender: AccountId, value: Balance) {
+            self.share_allowances.insert((owner, spender), &value);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+        }
+
+        /// Weight of `founder`'s vote on governance proposals under the configured `VoteWeighting`
+        fn voting_weight(&self, founder: &Founder) -> u128 {
+            match self.config.governance.weighting {
+                VoteWeighting::ByShares => self.balance_of(founder.id),
+                VoteWeighting::OnePerFounder => 1,
+            }
+        }
+
+        /// Returns `founder` if they accepted the tribe and can vote on its proposals
+        fn get_voting_founder(&self, founder: AccountId) -> Result<Founder, TribeError> {
+            let founder = self.get_founder(founder)?;
+            match founder.vote_action() {
+                VoteAction::Accepted => Ok(founder),
+                VoteAction::Pending => Err(TribeError::FounderVoteActionPending),
+                VoteAction::Rejected => Err(TribeError::FounderRejectedInvitation)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+        use crate::config::GovernanceConfig;
+        use crate::errors::MyDisplay;
+
+        const NAME: &str = "a test tribe";
+        const DEADLINE: BlockNumber = 10;
+
+        /// Points the off-chain callee at a contract account holding `balance`
+        fn set_contract_balance(balance: u128) {
+            let contract = AccountId::from([0x7; 32]);
+            ink_env::test::set_callee::<ink_env::DefaultEnvironment>(contract);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(contract, balance);
+        }
+
+        fn advance_blocks(count: BlockNumber) {
+            for _ in 0..count {
+                ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+            }
+        }
+
+        fn recorded_events() -> Vec<Event> {
+            ink_env::test::recorded_events()
+                .map(|event| <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid event data"))
+                .collect()
+        }
+
+//******************************** create_tribe  ********************************
+        #[ink::test]
+        fn create_tribe_success() {
+            //ACT
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ASSERT
+            assert_eq!(tribe.name, NAME.to_string());
+            assert_eq!(tribe.status, TribeStatus::Forming);
+        }
+
+        #[ink::test]
+        fn create_tribe_contains_only_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            assert!(tribe.founders.contains(alice));
+            let founder = tribe.get_founder(alice).expect("expected initial founder");
+
+            //ASSERT
+            assert_eq!(tribe.name, NAME.to_string());
+            assert_eq!(tribe.founder_count, 1);
+            assert_eq!(tribe.founder_ids.get(0), Some(alice));
+            assert!(founder.initial);  //assert only member of newly started tribe is the initial founder
+        }
+
+        #[ink::test]
+        fn create_tribe_accepts_for_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            //ACT
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ASSERT
+            let founder = tribe.get_founder(alice).expect("expected initial founder");
+            assert!(founder.is_accepted());
+            assert_eq!(founder.amount_funded(), 0);
+            assert_eq!(tribe.total_funded, 0);
+            assert_eq!(recorded_events().len(), 0);
+        }
+
+        #[ink::test]
+        fn create_tribe_credits_endowment_to_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+
+            //ACT
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ASSERT
+            let founder = tribe.get_founder(alice).expect("expected initial founder");
+            assert_eq!(founder.amount_funded(), 3000);
+            assert_eq!(tribe.total_funded, 3000);
+            assert_eq!(tribe.status, TribeStatus::Forming);
+            match &recorded_events()[0] {
+                Event::FounderFunded(event) => {
+                    assert_eq!(event.founder, alice);
+                    assert_eq!(event.amount, 3000);
+                    assert_eq!(event.total_funded, 3000);
+                },
+                _ => panic!("expected FounderFunded event"),
+            }
+            tribe.invite_founder(bob, 4000, true).expect("initial founder funds should not lock the roster");
+        }
+
+        #[ink::test]
+        fn create_tribe_refunds_endowment_above_amount_needed() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            set_contract_balance(7000);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(alice, 0);
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(7000);
+
+            //ACT
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ASSERT
+            let founder = tribe.get_founder(alice).expect("expected initial founder");
+            assert_eq!(founder.amount_funded(), 5000);
+            assert!(founder.is_funded());
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(alice), Ok(2000));
+            assert_eq!(tribe.status, TribeStatus::Forming);
+
+            tribe.accept_tribe().expect("should pass");
+            assert_eq!(tribe.status, TribeStatus::Active);
+        }
+
+//******************************** new_with_founders  ********************************
+        #[ink::test]
+        fn new_with_founders_should_invite_every_founder_in_order() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            //ACT
+            let tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, true), (charlie, 3000, false)]);
+
+            //ASSERT
+            assert_eq!(tribe.founder_count, 3);
+            assert_eq!(tribe.founder_ids.get(1), Some(bob));
+            assert_eq!(tribe.founder_ids.get(2), Some(charlie));
+            let bob_founder = tribe.get_founder(bob).expect("bob should be a founder");
+            assert!(bob_founder.required);
+            assert_eq!(bob_founder.amount_promised, 4000);
+            assert_eq!(tribe.get_founder(charlie).expect("charlie should be a founder").required, false);
+            assert_eq!(recorded_events().len(), 2);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "The initial founder can not be invited to join their own tribe")]
+        fn new_with_founders_should_fail_when_inviting_initial_founder() {
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(alice, 4000, true)]);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "AccountId already exists as a Founder")]
+        fn new_with_founders_should_fail_on_duplicate_founder() {
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, true), (bob, 3000, false)]);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Amount promised in pico must be greater than 0")]
+        fn new_with_founders_should_fail_on_zero_amount_promised() {
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 0, true)]);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Amount promised in pico must be greater than 0")]
+        fn new_should_fail_when_initial_amount_is_zero() {
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            TribeContract::new(NAME.to_string(), 0, DEADLINE);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Founding deadline must be after the current block")]
+        fn new_should_fail_when_founding_deadline_is_not_in_the_future() {
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            advance_blocks(DEADLINE);
+            TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+        }
+
+//******************************** activate_tribe  ********************************
+        #[ink::test]
+        fn activate_tribe_with_no_activity_should_have_no_effect() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.activate_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Forming)
+        }
+
+        #[ink::test]
+        fn active_tribe_with_funded_activity_should_enable() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            /* Update Alice in founders list to accept and fully fund tribe */
+            let mut alice_founder = tribe.get_founder(alice).expect("alice should be initial founder");
+            alice_founder.accept(0).expect("ok");
+            alice_founder.fund(5000).expect("ok");
+            tribe.save_founder(&alice_founder);
+
+            //ACT
+            let prev_status = tribe.status;
+            tribe.activate_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(prev_status, TribeStatus::Forming);
+            assert_eq!(tribe.status, TribeStatus::Active);
+        }
+
+//******************************** get_founder_list  ********************************
+        #[ink::test]
+        fn get_founder_list_should_return_vec() {
+            //ASSIGN
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            let founder_list = tribe.get_founder_list().expect("should pass");
+
+            //ASSERT
+            assert_eq!(founder_list.len(), 1);
+        }
+
+        #[ink::test]
+        fn get_founder_list_should_keep_invitation_order() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.invite_founder(charlie, 4000, true).expect("should pass");
+
+            //ACT
+            let founder_list = tribe.get_founder_list().expect("should pass");
+
+            //ASSERT
+            let ids: Vec<AccountId> = founder_list.iter().map(|founder| founder.id).collect();
+            assert_eq!(ids, ink_prelude::vec![alice, bob, charlie]);
+        }
+
+//******************************** get_founder  ********************************
+        #[ink::test]
+        fn get_founder_should_return_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            //ACT
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            match tribe.get_founder(alice) {
+                //ASSERT
+                Ok(alice_founder) => assert_eq!(alice_founder.id, alice),
+                Err(err) => panic!("founder not found; {}", err.fmt()),
+            }
+        }
+
+        #[ink::test]
+        fn get_founder_should_not_find_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob= AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            //ACT
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            match tribe.get_founder(bob) {
+                Ok(_) => assert!(false),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotAFounder, err),
+            }
+        }
+
+//******************************** save_founder  ********************************
+        #[ink::test]
+        fn save_founder_should_keep_tallies_in_step() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            assert_eq!(tribe.pending_founder_count, 1);
+
+            //ACT
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.founder_count, 2);
+            assert_eq!(tribe.pending_founder_count, 2);
+            assert_eq!(tribe.accepted_founder_count, 2);
+            assert_eq!(tribe.total_funded, 3000);
+        }
+
+//******************************** remove_founder  ********************************
+        #[ink::test]
+        fn remove_founder_should_close_gap_in_founder_ids() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.invite_founder(charlie, 4000, true).expect("should pass");
+            let bob_founder = tribe.get_founder(bob).expect("bob should be a founder");
+
+            //ACT
+            tribe.remove_founder(&bob_founder).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.founder_count, 2);
+            assert_eq!(tribe.founder_ids.get(1), Some(charlie));
+            assert_eq!(tribe.founder_ids.get(2), None);
+            assert_eq!(tribe.pending_founder_count, 2);
+            assert!(!tribe.founders.contains(bob));
+        }
+
+//******************************** accept_tribe  ********************************
+        #[ink::test]
+        fn accept_tribe_should_fail_when_tribe_is_defunct(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Defunct;
+            match tribe.accept_tribe() {
+                Ok(_) => assert!(false, "Should not accept tribe"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::TribeIsDefunct, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_tribe_should_fail_when_tribe_is_enabled(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Active;
+            match tribe.accept_tribe() {
+                Ok(_) => assert!(false, "Should not accept tribe"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_tribe_should_fail_when_caller_is_not_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.accept_tribe() {
+                Ok(_) => assert!(false, "Should not accept tribe"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_tribe_should_fail_when_caller_already_rejected_tribe() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.get_founder(alice) {
+                Ok(mut founder) => {
+                    // Mark founder as rejected
+                    founder.reject().expect("should reject");
+                    tribe.save_founder(&founder);
+
+                    match tribe.accept_tribe() {
+                        Ok(_) => assert!(false, "Should not accept tribe"),
+                        //ASSERT
+                        Err(err) => assert_eq!(TribeError::FounderRejectedInvitation, err, "actual error received {}", err.fmt())
+                    }
+
+                },
+                Err(err) => panic!("founder not found; Error={}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_tribe_should_mark_founder_as_accepted() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            let previous = tribe.get_founder(bob).expect("should get founder");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("Should have passed");
+
+            //ASSERT
+            match tribe.get_founder(bob) {
+                Ok(founder) => {
+                    assert_eq!(previous.is_accepted(), false);
+                    assert!(founder.is_accepted());
+                },
+                Err(err) => panic!("founder not found; Error={}", err.fmt())
+            }
+        }
+
+//******************************** invite_founder  ********************************
+        #[ink::test]
+        fn invite_founder_should_fail_when_tribe_is_defunct(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Defunct;
+            match tribe.invite_founder(bob, 4000, false) {
+                Ok(_) => assert!(false, "Invite founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::TribeIsDefunct, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_founder_should_fail_when_tribe_is_enabled(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Active;
+            match tribe.invite_founder(bob, 4000, false) {
+                Ok(_) => assert!(false, "Invite founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+            }
+
+        }
+
+        #[ink::test]
+        fn invite_founder_should_fail_when_caller_is_not_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.invite_founder(charlie, 4000, false) {
+                Ok(_) => assert!(false, "Invite founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::MissingRole, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_founder_should_fail_when_caller_is_not_the_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.invite_founder(charlie, 4000, false) {
+                Ok(_) => assert!(false, "Invite founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::MissingRole, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_founder_should_fail_to_invite_the_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.invite_founder(alice, 4000, false) {
+                Ok(_) => assert!(false, "Invite founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::CanNotInviteInitialFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_founder_should_fail_to_invite_same_account_more_than_once() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            //ACT
+            match tribe.invite_founder(bob, 4000, false) {
+                Ok(_) => assert!(false, "Invite founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderAlreadyInvited, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_founder_should_fail_once_a_founder_has_funded() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("bob should be able to accept tribe");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("bob should be able to fund tribe");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            match tribe.invite_founder(charlie, 4000, false) {
+                Ok(_) => assert!(false, "Invite founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::TribeIsLocked, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_founder_should_succeed() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            //ASSERT
+            let founders = tribe.get_founder_list().expect("should get founder list");
+            assert_eq!(founders.len(), 2);
+        }
+
+//******************************** fund_tribe  ********************************
+        #[ink::test]
+        fn fund_tribe_should_fail_when_tribe_is_defunct() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Defunct;
+            match tribe.fund_tribe() {
+                Ok(_) => assert!(false, "fund tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::TribeIsDefunct, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_fail_when_tribe_is_enabled(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Active;
+            match tribe.fund_tribe() {
+                Ok(_) => assert!(false, "fund tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_fail_when_caller_is_not_founder(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.fund_tribe() {
+                Ok(_) => assert!(false, "fund tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn fund_tribe_without_value_should_fail(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.fund_tribe() {
+                Ok(_) => assert!(false, "fund tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(err, TribeError::FundingAmountMustBeGreaterThanZero)
+            }
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_accept_funds(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            let funding = tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Forming);
+            assert_eq!(funding, (3000, 0));
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_accept_full_funding(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            let funding1 = tribe.fund_tribe().expect("should pass");
+
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(2000);
+            let funding2 = tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Active);
+            assert_eq!(funding1, (3000, 0));
+            assert_eq!(funding2, (5000, 0));
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_refund_excess_over_promise(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            set_contract_balance(6500);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(alice, 0);
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(6500);
+            let funding = tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            let founder = tribe.get_founder(alice).expect("should get founder");
+            assert_eq!(tribe.status, TribeStatus::Active);
+            assert_eq!(funding, (5000, 1500));
+            assert_eq!(founder.amount_funded(), 5000);
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(alice), Ok(1500));
+        }
+
+        //******************************** get_founder_status  ********************************
+        #[ink::test]
+        fn get_founder_status_should_return_not_found_message() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            let bob = AccountId::from([0x1; 32]);
+            match tribe.get_founder_status(bob) {
+                Ok(_) => assert!(false, "should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(err, TribeError::NotAFounder)
+            }
+        }
+
+        #[ink::test]
+        fn get_founder_status_should_return_founder_description() {
+
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            let status = tribe.get_founder_status(alice).expect("should pass");
+
+            ink_env::debug_println!("received status: {} ", status);
+
+            //ASSERT
+            assert_eq!(status, r#"{
+    "initial": true,
+    "required": true,
+    "rejected": false,
+    "completed": false,
+    "amount_promised": 5000,
+    "amount_funded": 0
+}"#);
+        }
+
+//******************************** get_tribe  ********************************
+        macro_rules! get_tribe_should_return_expected {
+            ($($name:ident: $value:expr,)*) => {
+            $(
+                #[ink::test]
+                fn $name() {
+                    //ASSIGN
+                    let (name, status, expected) = $value;
+                    let mut tribe = TribeContract::new(name.to_string(), 5000, DEADLINE);
+                    tribe.status = status;
+
+                    //ACT
+                    let result = tribe.get_tribe();
+
+                    //ASSERT
+                    assert_eq!(expected, result);
+                }
+            )*
+            }
+        }
+
+        get_tribe_should_return_expected! {
+            get_tribe_not_enabled_not_defunct: ("alice's massive tribe", TribeStatus::Forming, "{\n    \"name\": \"alice's massive tribe\",\n    \"enabled\": false,\n    \"defunct\": false\n}"),
+            get_tribe_enabled_not_defunct: ("yet another tribe", TribeStatus::Active, "{\n    \"name\": \"yet another tribe\",\n    \"enabled\": true,\n    \"defunct\": false\n}"),
+            get_tribe_not_enabled_defunct: ("a defunct tribe", TribeStatus::Defunct, "{\n    \"name\": \"a defunct tribe\",\n    \"enabled\": false,\n    \"defunct\": true\n}"),
+            get_tribe_dissolved: ("a dissolved tribe", TribeStatus::Dissolved, "{\n    \"name\": \"a dissolved tribe\",\n    \"enabled\": false,\n    \"defunct\": true\n}"),
+            get_tribe_escapes_name: ("the \"best\" tribe\\", TribeStatus::Forming, "{\n    \"name\": \"the \\\"best\\\" tribe\\\\\",\n    \"enabled\": false,\n    \"defunct\": false\n}"),
+        }
+
+//******************************** reject_tribe  ********************************
+
+        #[ink::test]
+        fn reject_tribe_should_fail_when_tribe_is_defunct(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Defunct;
+            match tribe.reject_tribe() {
+                Ok(_) => assert!(false, "reject tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(err, TribeError::TribeIsDefunct)
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_fail_when_tribe_is_enabled(){
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.status = TribeStatus::Active;
+            match tribe.reject_tribe() {
+                Ok(_) => assert!(false, "reject tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(err, TribeError::ActiveTribeCannotAcceptFounderAction)
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_fail_when_caller_is_not_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.reject_tribe() {
+                Ok(_) => assert!(false, "reject tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_succeed_and_mark_tribe_as_defunct() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            let prev_status = tribe.status;
+
+            //ACT
+            tribe.reject_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(prev_status, TribeStatus::Forming);
+
+            assert_eq!(tribe.status, TribeStatus::Defunct);
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_succeed_and_mark_founder_vote() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.reject_tribe().expect("should pass");
+
+            //ASSERT
+            match tribe.get_founder(alice) {
+                Ok(founder) => {
+                    assert!(founder.is_rejected());
+                },
+                Err(err) => panic!("founder not found; Error={}", err.fmt()),
+            }
+        }
+
+//******************************** claim_refund  ********************************
+        #[ink::test]
+        fn claim_refund_should_fail_when_caller_is_not_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.claim_refund() {
+                Ok(_) => assert!(false, "claim refund should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn claim_refund_should_fail_when_tribe_is_forming() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            match tribe.claim_refund() {
+                Ok(_) => assert!(false, "claim refund should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::RefundNotAvailable, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn claim_refund_should_fail_without_funds() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.reject_tribe().expect("should pass");
+
+            //ACT
+            match tribe.claim_refund() {
+                Ok(_) => assert!(false, "claim refund should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoFundsToRefund, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn claim_refund_should_return_funds_when_required_founder_rejects() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+            set_contract_balance(3000);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(bob, 0);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.reject_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            let refunded = tribe.claim_refund().expect("should pass");
+
+            //ASSERT
+            let bob_founder = tribe.get_founder(bob).expect("bob should be a founder");
+            assert_eq!(tribe.status, TribeStatus::Defunct);
+            assert_eq!(refunded, 3000);
+            assert_eq!(bob_founder.has_funds(), false);
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(bob), Ok(3000));
+        }
+
+        #[ink::test]
+        fn claim_refund_should_fail_when_refund_was_already_claimed() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+            set_contract_balance(3000);
+            tribe.reject_tribe().expect("should pass");
+            tribe.claim_refund().expect("should pass");
+
+            //ACT
+            match tribe.claim_refund() {
+                Ok(_) => assert!(false, "claim refund should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoFundsToRefund, err, "actual error received {}", err.fmt())
+            }
+        }
+
+//******************************** events  ********************************
+        #[ink::test]
+        fn invite_founder_should_emit_founder_invited() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+
+            //ASSERT
+            let events = recorded_events();
+            assert_eq!(events.len(), 1);
+            match &events[0] {
+                Event::FounderInvited(event) => {
+                    assert_eq!(event.founder, bob);
+                    assert_eq!(event.amount_promised, 4000);
+                    assert!(event.required);
+                },
+                _ => panic!("expected FounderInvited event"),
+            }
+        }
+
+        #[ink::test]
+        fn accept_tribe_should_emit_founder_accepted() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.accept_tribe().expect("should pass");
+
+            //ASSERT
+            let events = recorded_events();
+            assert_eq!(events.len(), 1);
+            match &events[0] {
+                Event::FounderAccepted(event) => assert_eq!(event.founder, alice),
+                _ => panic!("expected FounderAccepted event"),
+            }
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_emit_founder_funded_with_running_total() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(2000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            let events = recorded_events();
+            assert_eq!(events.len(), 3);
+            match &events[2] {
+                Event::FounderFunded(event) => {
+                    assert_eq!(event.founder, alice);
+                    assert_eq!(event.amount, 1000);
+                    assert_eq!(event.total_funded, 3000);
+                },
+                _ => panic!("expected FounderFunded event"),
+            }
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_emit_tribe_activated_when_fully_funded() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            let events = recorded_events();
+            assert_eq!(events.len(), 3);
+            match &events[2] {
+                Event::TribeActivated(event) => assert_eq!(event.total_funded, 5000),
+                _ => panic!("expected TribeActivated event"),
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_emit_founder_rejected_and_tribe_defunct() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.reject_tribe().expect("should pass");
+
+            //ASSERT
+            let events = recorded_events();
+            assert_eq!(events.len(), 2);
+            match &events[0] {
+                Event::FounderRejected(event) => {
+                    assert_eq!(event.founder, alice);
+                    assert!(event.required);
+                },
+                _ => panic!("expected FounderRejected event"),
+            }
+            match &events[1] {
+                Event::TribeDefunct(event) => assert_eq!(event.founder, Some(alice)),
+                _ => panic!("expected TribeDefunct event"),
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_by_optional_founder_should_not_emit_tribe_defunct() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
+
+            //ASSERT
+            let events = recorded_events();
+            assert_eq!(events.len(), 2);
+            match &events[1] {
+                Event::FounderRejected(event) => {
+                    assert_eq!(event.founder, bob);
+                    assert_eq!(event.required, false);
+                },
+                _ => panic!("expected FounderRejected event"),
+            }
+        }
+
+//******************************** finalize_founding  ********************************
+        #[ink::test]
+        fn finalize_founding_should_fail_before_deadline() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            advance_blocks(DEADLINE);
+
+            //ACT
+            match tribe.finalize_founding() {
+                Ok(_) => assert!(false, "finalize founding should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FoundingDeadlineNotReached, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn finalize_founding_should_fail_when_tribe_is_enabled() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.status = TribeStatus::Active;
+            advance_blocks(DEADLINE + 1);
+
+            //ACT
+            match tribe.finalize_founding() {
+                Ok(_) => assert!(false, "finalize founding should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn finalize_founding_should_fail_when_tribe_is_defunct() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            advance_blocks(DEADLINE + 1);
+            tribe.finalize_founding().expect("should pass");
+
+            //ACT
+            match tribe.finalize_founding() {
+                Ok(_) => assert!(false, "finalize founding should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::TribeIsDefunct, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn finalize_founding_should_activate_tribe_funded_by_endowment() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            assert_eq!(tribe.status, TribeStatus::Forming);
+            advance_blocks(DEADLINE + 1);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.finalize_founding().expect("anyone should be able to finalize");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Active);
+            assert_eq!(tribe.get_founder(alice).expect("alice should be a founder").amount_funded(), 5000);
+            assert_eq!(tribe.balance_of(alice), 5000);
+            match recorded_events().last() {
+                Some(Event::TribeActivated(event)) => assert_eq!(event.total_funded, 5000),
+                _ => panic!("expected TribeActivated event"),
+            }
+        }
+
+        #[ink::test]
+        fn finalize_founding_should_mark_tribe_defunct_and_refund_founders() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+
+            set_contract_balance(6000);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(alice, 0);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(bob, 0);
+            advance_blocks(DEADLINE + 1);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.finalize_founding().expect("anyone should be able to finalize");
+
+            //ASSERT
+            let founders = tribe.get_founder_list().expect("should get list");
+            assert_eq!(tribe.status, TribeStatus::Defunct);
+            assert!(founders.iter().all(|founder| !founder.has_funds()));
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(alice), Ok(5000));
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(bob), Ok(1000));
+            match recorded_events().last() {
+                Some(Event::TribeDefunct(event)) => assert_eq!(event.founder, None),
+                _ => panic!("expected TribeDefunct event"),
+            }
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_fail_after_founding_deadline() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            advance_blocks(DEADLINE + 1);
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            match tribe.fund_tribe() {
+                Ok(_) => assert!(false, "fund tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FoundingDeadlinePassed, err, "actual error received {}", err.fmt())
+            }
+        }
+
+//******************************** revoke_invitation  ********************************
+        #[ink::test]
+        fn revoke_invitation_should_fail_when_caller_is_not_the_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.invite_founder(charlie, 4000, false).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.revoke_invitation(charlie) {
+                Ok(_) => assert!(false, "revoke invitation should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::MissingRole, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn revoke_invitation_should_fail_for_unknown_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.revoke_invitation(bob) {
+                Ok(_) => assert!(false, "revoke invitation should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn revoke_invitation_should_fail_to_revoke_the_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.revoke_invitation(alice) {
+                Ok(_) => assert!(false, "revoke invitation should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::CanNotRevokeInitialFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn revoke_invitation_should_fail_when_founder_has_funds() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            match tribe.revoke_invitation(bob) {
+                Ok(_) => assert!(false, "revoke invitation should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderHasFunds, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn revoke_invitation_should_fail_when_founder_has_accepted() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            match tribe.revoke_invitation(bob) {
+                Ok(_) => assert!(false, "revoke invitation should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderNotPending, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn revoke_invitation_should_remove_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            //ACT
+            tribe.revoke_invitation(bob).expect("should pass");
+
+            //ASSERT
+            let founders = tribe.get_founder_list().expect("should get founder list");
+            assert_eq!(founders.len(), 1);
+            assert_eq!(tribe.get_founder(bob).err(), Some(TribeError::NotAFounder));
+            match recorded_events().last() {
+                Some(Event::FounderRevoked(event)) => assert_eq!(event.founder, bob),
+                _ => panic!("expected FounderRevoked event"),
+            }
+        }
+
+        #[ink::test]
+        fn revoke_invitation_should_activate_tribe_when_only_revoked_founder_was_pending() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+            assert_eq!(tribe.status, TribeStatus::Forming);
+
+            //ACT
+            tribe.revoke_invitation(bob).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Active);
+        }
+
+//******************************** withdraw_contribution  ********************************
+        #[ink::test]
+        fn withdraw_contribution_should_fail_when_tribe_is_enabled() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            match tribe.withdraw_contribution(1000) {
+                Ok(_) => assert!(false, "withdraw contribution should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn withdraw_contribution_should_fail_when_amount_exceeds_funding() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(2000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            match tribe.withdraw_contribution(2500) {
+                Ok(_) => assert!(false, "withdraw contribution should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::WithdrawalExceedsAmountFunded, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn withdraw_contribution_should_return_funds_to_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+            set_contract_balance(3000);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(alice, 0);
+
+            //ACT
+            let remaining = tribe.withdraw_contribution(1000).expect("should pass");
+
+            //ASSERT
+            let founder = tribe.get_founder(alice).expect("should get founder");
+            assert_eq!(remaining, 2000);
+            assert_eq!(founder.amount_funded(), 2000);
+            assert!(founder.has_pending_activity());
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(alice), Ok(1000));
+            match recorded_events().last() {
+                Some(Event::FounderWithdrew(event)) => {
+                    assert_eq!(event.founder, alice);
+                    assert_eq!(event.amount, 1000);
+                    assert_eq!(event.total_funded, 2000);
+                },
+                _ => panic!("expected FounderWithdrew event"),
+            }
+        }
+
+//******************************** propose_amount_promised / confirm_amount_promised  ********************************
+        #[ink::test]
+        fn propose_amount_promised_should_fail_for_own_amount() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.propose_amount_promised(alice, 6000) {
+                Ok(_) => assert!(false, "propose amount promised should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::CanNotProposeOwnAmount, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn propose_amount_promised_should_fail_when_caller_is_not_the_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.propose_amount_promised(bob, 1000) {
+                Ok(_) => assert!(false, "propose amount promised should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::MissingRole, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn confirm_amount_promised_should_fail_without_proposal() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.confirm_amount_promised() {
+                Ok(_) => assert!(false, "confirm amount promised should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoAmendmentPending, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn confirm_amount_promised_should_only_apply_to_the_proposed_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            tribe.propose_amount_promised(bob, 2000).expect("should pass");
+
+            //ACT
+            match tribe.confirm_amount_promised() {
+                Ok(_) => assert!(false, "confirm amount promised should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoAmendmentPending, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn confirm_amount_promised_should_refund_excess_and_activate_tribe() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.propose_amount_promised(bob, 2500).expect("should pass");
+            set_contract_balance(8000);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(bob, 0);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            let refunded = tribe.confirm_amount_promised().expect("should pass");
+
+            //ASSERT
+            let bob_founder = tribe.get_founder(bob).expect("bob should be a founder");
+            assert_eq!(refunded, 500);
+            assert_eq!(bob_founder.amount_promised, 2500);
+            assert_eq!(bob_founder.amount_funded(), 2500);
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(bob), Ok(500));
+            assert_eq!(tribe.status, TribeStatus::Active);
+        }
+
+//******************************** get_tribe_status / get_founder_vote  ********************************
+        #[ink::test]
+        fn get_tribe_status_should_return_forming_for_new_tribe() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            let status = tribe.get_tribe_status();
+
+            //ASSERT
+            assert_eq!(status, TribeStatus::Forming);
+        }
+
+        #[ink::test]
+        fn get_founder_vote_should_track_founder_answers() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            let pending = tribe.get_founder_vote(bob).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(pending, VoteAction::Pending);
+            assert_eq!(tribe.get_founder_vote(bob), Ok(VoteAction::Rejected));
+            assert_eq!(tribe.get_founder_vote(alice), Ok(VoteAction::Accepted));
+        }
+
+        #[ink::test]
+        fn accept_tribe_should_fail_after_founder_rejected() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
+
+            //ACT
+            match tribe.accept_tribe() {
+                Ok(_) => assert!(false, "accept tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderRejectedInvitation, err, "actual error received {}", err.fmt())
+            }
+        }
+
+//******************************** dissolve_tribe  ********************************
+        #[ink::test]
+        fn dissolve_tribe_should_fail_when_tribe_is_forming() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.dissolve_tribe() {
+                Ok(_) => assert!(false, "dissolve tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::InvalidStatusTransition, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn dissolve_tribe_should_fail_while_refunds_are_outstanding() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+            tribe.status = TribeStatus::Defunct;
+
+            //ACT
+            match tribe.dissolve_tribe() {
+                Ok(_) => assert!(false, "dissolve tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::RefundsOutstanding, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn dissolve_tribe_should_succeed_once_refunds_are_claimed() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+            tribe.reject_tribe().expect("should pass");
+            set_contract_balance(3000);
+            tribe.claim_refund().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.dissolve_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.get_tribe_status(), TribeStatus::Dissolved);
+            assert_eq!(recorded_events().last().map(|event| matches!(event, Event::TribeDissolved(_))), Some(true));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            match tribe.accept_tribe() {
+                Ok(_) => assert!(false, "accept tribe should not pass"),
+                Err(err) => assert_eq!(TribeError::TribeIsDissolved, err, "actual error received {}", err.fmt())
+            }
+        }
+
+//******************************** get_tribe_info / get_founder_info  ********************************
+        #[ink::test]
+        fn get_tribe_info_should_return_tribe_state() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            let info = tribe.get_tribe_info();
+
+            //ASSERT
+            assert_eq!(info, TribeInfo {
+                name: NAME.to_string(),
+                status: TribeStatus::Forming,
+                founding_deadline: DEADLINE,
+                founder_count: 2,
+                total_funded: 3000,
+                roster_frozen: false,
+            });
+        }
+
+        #[ink::test]
+        fn get_founder_info_should_fail_when_founder_not_found() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.get_founder_info(bob) {
+                Ok(_) => assert!(false, "get founder info should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn get_founder_info_should_return_founder_state() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            tribe.propose_amount_promised(bob, 2000).expect("should pass");
+
+            //ACT
+            let info = tribe.get_founder_info(bob).expect("should pass");
+
+            //ASSERT
+            assert_eq!(info, FounderInfo {
+                id: bob,
+                initial: false,
+                required: false,
+                vote_action: VoteAction::Pending,
+                amount_promised: 4000,
+                amount_funded: 0,
+                amount_proposed: Some(2000),
+                amount_refundable: 0,
+                amount_forfeited: 0,
+                accepted_block: None,
+                installments: Vec::new(),
+                installments_paid: 0,
+                completed: true,
+            });
+        }
+
+//******************************** activation_policy  ********************************
+        #[ink::test]
+        #[should_panic(expected = "Activation policy threshold is out of range")]
+        fn new_with_config_should_fail_on_invalid_activation_policy() {
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), TribeConfig { activation_policy: ActivationPolicy::PercentageFunded(0), ..TribeConfig::default() });
+        }
+
+        #[ink::test]
+        fn get_tribe_config_should_return_config() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let config = TribeConfig { activation_policy: ActivationPolicy::MinimumTotalFunded(8000), ..TribeConfig::default() };
+
+            //ACT
+            let tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), config);
+
+            //ASSERT
+            assert_eq!(tribe.get_tribe_config(), config);
+            assert_eq!(TribeContract::new(NAME.to_string(), 5000, DEADLINE).get_tribe_config(), TribeConfig::default());
+        }
+
+        #[ink::test]
+        fn required_funded_plus_optional_policy_should_not_wait_for_slow_optional_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false), (charlie, 3000, false)],
+                TribeConfig { activation_policy: ActivationPolicy::RequiredFundedPlusOptional(1), ..TribeConfig::default() });
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(4000);
+            tribe.fund_tribe().expect("should pass");
+            let status_before_required = tribe.status;
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(status_before_required, TribeStatus::Forming);
+            assert_eq!(tribe.status, TribeStatus::Active);
+        }
+
+        #[ink::test]
+        fn minimum_total_funded_policy_should_activate_once_reached() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, true)],
+                TribeConfig { activation_policy: ActivationPolicy::MinimumTotalFunded(6000), ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+            let status_below_minimum = tribe.status;
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(status_below_minimum, TribeStatus::Forming);
+            assert_eq!(tribe.status, TribeStatus::Active);
+        }
+
+        #[ink::test]
+        fn minimum_total_funded_policy_should_ignore_rejected_founder_funds() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false), (charlie, 4000, false)],
+                TribeConfig { activation_policy: ActivationPolicy::MinimumTotalFunded(7000), ..TribeConfig::default() });
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(4000);
+            tribe.fund_tribe().expect("should pass");
+            tribe.reject_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.total_funded, 9000);
+            assert_eq!(tribe.status, TribeStatus::Forming);
+        }
+
+        #[ink::test]
+        fn percentage_funded_policy_should_activate_at_threshold() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 6000, DEADLINE, vec![(bob, 4000, true)],
+                TribeConfig { activation_policy: ActivationPolicy::PercentageFunded(75), ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(6000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            let status_at_seventy_percent = tribe.status;
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(500);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(status_at_seventy_percent, TribeStatus::Forming);
+            assert_eq!(tribe.status, TribeStatus::Active);
+        }
+
+//******************************** funding_target  ********************************
+        #[ink::test]
+        #[should_panic(expected = "Funding target soft cap must be greater than 0 and no more than the hard cap")]
+        fn new_with_config_should_fail_on_invalid_funding_target() {
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(),
+                TribeConfig { activation_policy: ActivationPolicy::FundingTarget { soft_cap: 9000, hard_cap: 8000 }, ..TribeConfig::default() });
+        }
+
+        #[ink::test]
+        fn funding_target_should_accept_contributions_beyond_amount_promised() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 1000, DEADLINE, Vec::new(),
+                TribeConfig { activation_policy: ActivationPolicy::FundingTarget { soft_cap: 10000, hard_cap: 20000 }, ..TribeConfig::default() });
+
+            //ACT
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(4000);
+            let result = tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(result, (4000, 0));
+            assert_eq!(tribe.total_funded, 4000);
+            assert_eq!(tribe.status, TribeStatus::Forming);
+        }
+
+        #[ink::test]
+        fn funding_target_should_activate_at_soft_cap() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 1000, DEADLINE, vec![(bob, 1000, false)],
+                TribeConfig { activation_policy: ActivationPolicy::FundingTarget { soft_cap: 10000, hard_cap: 20000 }, ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(4000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(6000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Active);
+            assert_eq!(tribe.total_funded, 10000);
+        }
+
+        #[ink::test]
+        fn funding_target_should_scale_back_pro_rata_above_hard_cap() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 1000, DEADLINE, vec![(bob, 1000, false)],
+                TribeConfig { activation_policy: ActivationPolicy::FundingTarget { soft_cap: 10000, hard_cap: 10000 }, ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(6000);
+            tribe.fund_tribe().expect("should pass");
+            set_contract_balance(15000);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(alice, 0);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(bob, 0);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(9000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Active);
+            assert_eq!(tribe.total_funded, 10000);
+            assert_eq!(tribe.get_founder(alice).expect("alice should be a founder").amount_funded(), 4000);
+            assert_eq!(tribe.get_founder(bob).expect("bob should be a founder").amount_funded(), 6000);
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(alice), Ok(2000));
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(bob), Ok(3000));
+            let scaled_back = recorded_events().into_iter().filter(|event| matches!(event, Event::ContributionScaledBack(_))).count();
+            assert_eq!(scaled_back, 2);
+        }
+
+        #[ink::test]
+        fn claim_refund_should_fail_for_active_tribe_without_scaled_back_funds() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 1000, DEADLINE, Vec::new(),
+                TribeConfig { activation_policy: ActivationPolicy::FundingTarget { soft_cap: 5000, hard_cap: 10000 }, ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(6000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            match tribe.claim_refund() {
+                Ok(_) => assert!(false, "claim refund should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::RefundNotAvailable, err, "actual error received {}", err.fmt())
+            }
+        }
+
+//******************************** installments  ********************************
+        #[ink::test]
+        fn invite_founder_with_installments_should_promise_sum_of_installments() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            let installments = vec![Installment { amount: 1000, due_block: 4 }, Installment { amount: 3000, due_block: DEADLINE }];
+
+            //ACT
+            tribe.invite_founder_with_installments(bob, installments.clone(), true).expect("should pass");
+
+            //ASSERT
+            let info = tribe.get_founder_info(bob).expect("bob should be a founder");
+            assert_eq!(info.amount_promised, 4000);
+            assert_eq!(info.installments, installments);
+            assert_eq!(info.installments_paid, 0);
+        }
+
+        #[ink::test]
+        fn invite_founder_with_installments_should_fail_when_due_after_deadline() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            match tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: DEADLINE + 1 }], true) {
+                Ok(_) => assert!(false, "invite founder with installments should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::InvalidInstallmentPlan, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_founder_with_installments_should_fail_when_caller_is_not_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.invite_founder_with_installments(charlie, vec![Installment { amount: 1000, due_block: 4 }], false) {
+                Ok(_) => assert!(false, "invite founder with installments should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::MissingRole, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn get_overdue_installments_should_list_missed_installments() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 8 }], true).expect("should pass");
+            let on_time = tribe.get_overdue_installments(bob).expect("should pass");
+
+            //ACT
+            advance_blocks(3);
+            let overdue = tribe.get_overdue_installments(bob).expect("should pass");
+
+            //ASSERT
+            assert_eq!(on_time, Vec::<u32>::new());
+            assert_eq!(overdue, vec![0]);
+        }
+
+        #[ink::test]
+        fn enforce_installments_should_fail_when_nothing_is_overdue() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }], false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(3);
+
+            //ACT
+            match tribe.enforce_installments(bob) {
+                Ok(_) => assert!(false, "enforce installments should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoInstallmentOverdue, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn enforce_installments_should_reject_optional_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 8 }], false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            advance_blocks(3);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.enforce_installments(bob).expect("should pass");
+
+            //ASSERT
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").is_rejected());
+            assert_eq!(tribe.status, TribeStatus::Forming);
+            let events = recorded_events();
+            match &events[events.len() - 2] {
+                Event::InstallmentMissed(event) => {
+                    assert_eq!(event.founder, bob);
+                    assert_eq!(event.installment, 0);
+                },
+                _ => panic!("expected InstallmentMissed event"),
+            }
+        }
+
+        #[ink::test]
+        fn enforce_installments_should_charge_rejection_penalty() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(),
+                TribeConfig { commitment_rule: CommitmentRule::Penalty(50), ..TribeConfig::default() });
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 8 }], false).expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(2000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(9);
+
+            //ACT
+            tribe.enforce_installments(bob).expect("should pass");
+
+            //ASSERT
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").is_rejected());
+            assert_eq!(tribe.get_founder(bob).expect("bob should be a founder").amount_funded(), 500);
+            assert_eq!(tribe.get_founder(alice).expect("alice should be a founder").amount_refundable(), 500);
+        }
+
+        #[ink::test]
+        fn enforce_installments_should_forfeit_funds_when_rejection_is_forbidden() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(),
+                TribeConfig { commitment_rule: CommitmentRule::Forbidden, ..TribeConfig::default() });
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 8 }], false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(9);
+
+            //ACT
+            tribe.enforce_installments(bob).expect("should pass");
+
+            //ASSERT
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").is_rejected());
+            assert_eq!(tribe.forfeited_funds, 1000);
+            assert_eq!(tribe.claim_refund(), Err(TribeError::NoFundsToRefund));
+        }
+
+        #[ink::test]
+        fn enforce_installments_should_make_tribe_defunct_for_required_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 8 }], true).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(9);
+
+            //ACT
+            tribe.enforce_installments(bob).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Defunct);
+            set_contract_balance(1000);
+            assert_eq!(tribe.claim_refund(), Ok(1000));
+        }
+
+//******************************** vote_to_eject  ********************************
+        fn eject_config(laggard_funds: LaggardFunds) -> TribeConfig {
+            TribeConfig { funding_grace_period: Some(3), laggard_funds, ..TribeConfig::default() }
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_fail_without_grace_period() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)]);
+
+            //ACT
+            match tribe.vote_to_eject(bob) {
+                Ok(_) => assert!(false, "vote to eject should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::EjectionDisabled, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_fail_within_grace_period() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)], eject_config(LaggardFunds::Refunded));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            advance_blocks(3);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            match tribe.vote_to_eject(bob) {
+                Ok(_) => assert!(false, "vote to eject should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderNotLagging, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_fail_for_own_seat() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), eject_config(LaggardFunds::Refunded));
+
+            //ACT
+            match tribe.vote_to_eject(alice) {
+                Ok(_) => assert!(false, "vote to eject should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::CanNotVoteToEjectSelf, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_wait_for_majority_and_reject_duplicates() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false), (charlie, 1000, false), (dave, 1000, false)],
+                eject_config(LaggardFunds::Refunded));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            advance_blocks(4);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let ejected = tribe.vote_to_eject(bob).expect("should pass");
+            let duplicate = tribe.vote_to_eject(bob);
+
+            //ASSERT
+            assert!(!ejected);
+            assert_eq!(duplicate, Err(TribeError::EjectionVoteAlreadyCast));
+            assert_eq!(tribe.get_eject_votes(bob), 1);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            assert_eq!(tribe.vote_to_eject(bob), Ok(true));
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").is_rejected());
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_let_ejected_founder_claim_refund() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), eject_config(LaggardFunds::Refunded));
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 3 }], false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(4);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.vote_to_eject(bob).expect("should pass");
+
+            //ASSERT
+            set_contract_balance(1000);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(tribe.claim_refund(), Ok(1000));
+            assert_eq!(tribe.status, TribeStatus::Forming);
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_forfeit_partial_funds_when_configured() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), eject_config(LaggardFunds::Forfeited));
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 3 }], false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(4);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.vote_to_eject(bob).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.forfeited_funds, 1000);
+            assert_eq!(tribe.total_funded, 0);
+            match recorded_events().iter().find(|event| matches!(event, Event::FounderEjected(_))) {
+                Some(Event::FounderEjected(event)) => assert_eq!(event.forfeited, 1000),
+                _ => panic!("expected FounderEjected event"),
+            }
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(tribe.claim_refund(), Err(TribeError::NoFundsToRefund));
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_make_tribe_defunct_for_required_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), eject_config(LaggardFunds::Forfeited));
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 3 }], true).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(4);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.vote_to_eject(bob).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Defunct);
+            assert_eq!(tribe.forfeited_funds, 0);
+            set_contract_balance(1000);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(tribe.claim_refund(), Ok(1000));
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_eject_partially_funded_founder_without_installments() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)], eject_config(LaggardFunds::Forfeited));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(4);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let ejected = tribe.vote_to_eject(bob).expect("should pass");
+
+            //ASSERT
+            assert!(ejected);
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").is_rejected());
+            assert_eq!(tribe.forfeited_funds, 1);
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_fail_while_installments_are_on_schedule() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), eject_config(LaggardFunds::Forfeited));
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 8 }], false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(6);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let on_schedule = tribe.vote_to_eject(bob);
+            advance_blocks(3);
+            let overdue = tribe.vote_to_eject(bob);
+
+            //ASSERT
+            assert_eq!(on_schedule, Err(TribeError::FounderNotLagging));
+            assert_eq!(overdue, Ok(true));
+            assert_eq!(tribe.forfeited_funds, 1000);
+        }
+
+        #[ink::test]
+        fn vote_to_eject_should_follow_transferred_seats() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let eve = AccountId::from([0x4; 32]);
+            let frank = AccountId::from([0x5; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false), (charlie, 1000, false), (dave, 1000, false)],
+                eject_config(LaggardFunds::Refunded));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            advance_blocks(4);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.vote_to_eject(bob).expect("should pass");
+            tribe.transfer_founder_seat(eve).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(eve);
+            tribe.accept_founder_seat(charlie).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.transfer_founder_seat(frank).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(frank);
+            tribe.accept_founder_seat(bob).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(eve);
+            let result = tribe.vote_to_eject(frank);
+
+            //ASSERT
+            assert_eq!(result, Err(TribeError::EjectionVoteAlreadyCast));
+            assert_eq!(tribe.get_eject_votes(frank), 1);
+            assert_eq!(tribe.get_eject_votes(bob), 0);
+            assert!(!tribe.get_founder(frank).expect("frank should be a founder").is_rejected());
+        }
+
+//******************************** roster  ********************************
+        #[ink::test]
+        fn invite_founder_should_pass_after_optional_founder_rejects() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.invite_founder(charlie, 4000, false).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.founder_count, 3);
+        }
+
+        #[ink::test]
+        fn fund_tribe_should_not_freeze_roster_when_configured() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)],
+                TribeConfig { freeze_roster_on_funding: false, ..TribeConfig::default() });
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.invite_founder(charlie, 4000, false).expect("should pass");
+
+            //ASSERT
+            assert!(!tribe.get_tribe_info().roster_frozen);
+        }
+
+        #[ink::test]
+        fn freeze_roster_should_fail_when_caller_is_not_initial_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)]);
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            match tribe.freeze_roster() {
+                Ok(_) => assert!(false, "freeze roster should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::MissingRole, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn freeze_roster_should_stop_invitations() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+
+            //ACT
+            tribe.freeze_roster().expect("should pass");
+
+            //ASSERT
+            assert!(matches!(recorded_events().last(), Some(Event::RosterFrozen(_))));
+            match tribe.invite_founder(bob, 4000, false) {
+                Ok(_) => assert!(false, "invite founder should not pass"),
+                Err(err) => assert_eq!(TribeError::TribeIsLocked, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_replacement_should_fail_when_founder_has_not_rejected() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)]);
+
+            //ACT
+            match tribe.invite_replacement(bob, charlie) {
+                Ok(_) => assert!(false, "invite replacement should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderNotRejected, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_replacement_should_fail_for_required_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, true)]);
+            let mut bob_founder = tribe.get_founder(bob).expect("bob should be a founder");
+            bob_founder.reject().expect("should reject");
+            tribe.save_founder(&bob_founder);
+
+            //ACT
+            match tribe.invite_replacement(bob, charlie) {
+                Ok(_) => assert!(false, "invite replacement should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::CanNotReplaceRequiredFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn invite_replacement_should_invite_substitute_into_frozen_roster() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)]);
+            tribe.freeze_roster().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.invite_replacement(bob, charlie).expect("should pass");
+
+            //ASSERT
+            let charlie_founder = tribe.get_founder(charlie).expect("charlie should be a founder");
+            assert_eq!(charlie_founder.amount_promised, 4000);
+            assert!(!charlie_founder.required);
+            match recorded_events().last() {
+                Some(Event::FounderReplaced(event)) => {
+                    assert_eq!(event.rejected_founder, bob);
+                    assert_eq!(event.founder, charlie);
+                },
+                _ => panic!("expected FounderReplaced event"),
+            }
+            assert_eq!(tribe.invite_replacement(bob, dave), Err(TribeError::FounderAlreadyReplaced));
+        }
+
+        #[ink::test]
+        fn invite_replacement_should_pass_again_once_replacement_is_revoked() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.invite_replacement(bob, charlie).expect("should pass");
+            tribe.revoke_invitation(charlie).expect("should pass");
+
+            //ACT
+            tribe.invite_replacement(bob, dave).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.get_founder(dave).expect("dave should be a founder").amount_promised, 4000);
+            assert_eq!(tribe.invite_replacement(bob, charlie), Err(TribeError::FounderAlreadyReplaced));
+        }
+
+        #[ink::test]
+        fn invite_replacement_should_fail_for_transferred_seat_already_replaced() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let eve = AccountId::from([0x4; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_founders(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.invite_replacement(bob, charlie).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.transfer_founder_seat(eve).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(eve);
+            tribe.accept_founder_seat(bob).expect("should pass");
+
+            //ACT
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            match tribe.invite_replacement(eve, dave) {
+                Ok(_) => assert!(false, "invite replacement should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderAlreadyReplaced, err, "actual error received {}", err.fmt())
+            }
+        }
+
+//******************************** commitment_rule  ********************************
+        fn commitment_tribe(rule: CommitmentRule) -> TribeContract {
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false), (charlie, 2000, false)],
+                TribeConfig { commitment_rule: rule, ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(2000);
+            tribe.fund_tribe().expect("should pass");
+            tribe
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_fail_after_funding_when_forbidden() {
+            //ASSIGN
+            let mut tribe = commitment_tribe(CommitmentRule::Forbidden);
+
+            //ACT
+            match tribe.reject_tribe() {
+                Ok(_) => assert!(false, "reject tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::RejectionAfterFundingForbidden, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_pass_without_funds_when_forbidden() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)],
+                TribeConfig { commitment_rule: CommitmentRule::Forbidden, ..TribeConfig::default() });
 
             //ACT
-            tribe.activate_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.reject_tribe().expect("should pass");
 
             //ASSERT
-            assert_eq!(tribe.enabled, false)
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").is_rejected());
         }
 
         #[ink::test]
-        fn active_tribe_with_funded_activity_should_enable() {
+        fn reject_tribe_should_respect_cooling_off_block() {
+            //ASSIGN
+            let mut tribe = commitment_tribe(CommitmentRule::CoolingOff(2));
+            advance_blocks(3);
+
+            //ACT
+            match tribe.reject_tribe() {
+                Ok(_) => assert!(false, "reject tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::CoolingOffPeriodEnded, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_pass_within_cooling_off_block() {
+            //ASSIGN
+            let mut tribe = commitment_tribe(CommitmentRule::CoolingOff(2));
+            advance_blocks(2);
+
+            //ACT
+            tribe.reject_tribe().expect("should pass");
+
+            //ASSERT
+            let bob = AccountId::from([0x1; 32]);
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").is_rejected());
+        }
+
+        #[ink::test]
+        fn reject_tribe_should_require_penalty_when_configured() {
+            //ASSIGN
+            let mut tribe = commitment_tribe(CommitmentRule::Penalty(20));
+
+            //ACT
+            match tribe.reject_tribe() {
+                Ok(_) => assert!(false, "reject tribe should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::RejectionRequiresPenalty, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn withdraw_contribution_should_not_bypass_forbidden_rejection() {
+            //ASSIGN
+            let mut tribe = commitment_tribe(CommitmentRule::Forbidden);
+
+            //ACT
+            match tribe.withdraw_contribution(2000) {
+                Ok(_) => assert!(false, "withdraw contribution should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::RejectionAfterFundingForbidden, err, "actual error received {}", err.fmt())
+            }
+            assert_eq!(tribe.reject_tribe(), Err(TribeError::RejectionAfterFundingForbidden));
+        }
+
+        #[ink::test]
+        fn withdraw_contribution_should_not_bypass_rejection_penalty() {
+            //ASSIGN
+            let bob = AccountId::from([0x1; 32]);
+            let mut tribe = commitment_tribe(CommitmentRule::Penalty(30));
+
+            //ACT
+            match tribe.withdraw_contribution(2000) {
+                Ok(_) => assert!(false, "withdraw contribution should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::RejectionRequiresPenalty, err, "actual error received {}", err.fmt())
+            }
+            assert_eq!(tribe.reject_tribe(), Err(TribeError::RejectionRequiresPenalty));
+            assert_eq!(tribe.reject_tribe_with_penalty(), Ok(600));
+            assert_eq!(tribe.get_founder(bob).expect("bob should be a founder").amount_funded(), 1400);
+        }
+
+        #[ink::test]
+        fn reject_tribe_with_penalty_should_fail_without_penalty_rule() {
+            //ASSIGN
+            let mut tribe = commitment_tribe(CommitmentRule::Unrestricted);
+
+            //ACT
+            match tribe.reject_tribe_with_penalty() {
+                Ok(_) => assert!(false, "reject tribe with penalty should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoRejectionPenalty, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn reject_tribe_with_penalty_should_redistribute_pro_rata() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let mut tribe = commitment_tribe(CommitmentRule::Penalty(30));
+
+            //ACT
+            let penalty = tribe.reject_tribe_with_penalty().expect("should pass");
+
+            //ASSERT
+            assert_eq!(penalty, 600);
+            assert_eq!(tribe.get_founder(bob).expect("bob should be a founder").amount_funded(), 1400);
+            assert_eq!(tribe.get_founder(alice).expect("alice should be a founder").amount_refundable(), 450);
+            assert_eq!(tribe.get_founder(charlie).expect("charlie should be a founder").amount_refundable(), 150);
+            assert_eq!(tribe.total_funded, 5400);
+            set_contract_balance(6000);
+            assert_eq!(tribe.claim_refund(), Ok(1400));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            assert_eq!(tribe.claim_refund(), Ok(150));
+            assert_eq!(tribe.get_founder(charlie).expect("charlie should be a founder").amount_funded(), 1000);
+        }
+
+        #[ink::test]
+        fn reject_tribe_with_penalty_should_not_overflow_on_large_funding() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 1 << 126, false), (charlie, 1 << 126, false)],
+                TribeConfig { commitment_rule: CommitmentRule::Penalty(50), ..TribeConfig::default() });
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1 << 126);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            tribe.fund_tribe().expect("should pass");
 
-            /* Update Alice in founders list to accept and fully fund tribe */
-            let alice_index = tribe.get_founder_index(alice).expect("alice should be initial founder");
-            let mut founders = tribe.get_founder_list().expect("should get list");
-            founders[alice_index].vote_action = FOUNDER_ACCEPTED;
-            founders[alice_index].fund(5000).expect("ok");
-            tribe.founders.insert(0, &founders);
+            //ACT
+            let penalty = tribe.reject_tribe_with_penalty().expect("should pass");
+
+            //ASSERT
+            assert_eq!(penalty, 1 << 125);
+            assert_eq!(tribe.get_founder(bob).expect("bob should be a founder").amount_funded(), 1 << 125);
+            assert_eq!(tribe.get_founder(charlie).expect("charlie should be a founder").amount_refundable(), 1 << 125);
+        }
+
+//******************************** transfer_founder_seat  ********************************
+        #[ink::test]
+        fn transfer_founder_seat_should_fail_for_account_in_roster() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
 
             //ACT
-            let prev_enabled = tribe.enabled;
-            tribe.activate_tribe().expect("should pass");
+            match tribe.transfer_founder_seat(bob) {
+                Ok(_) => assert!(false, "transfer founder seat should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::FounderAlreadyInvited, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_founder_seat_should_fail_without_transfer() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.transfer_founder_seat(charlie).expect("should pass");
+            let dave = AccountId::from([0x3; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+
+            //ACT
+            match tribe.accept_founder_seat(bob) {
+                Ok(_) => assert!(false, "accept founder seat should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoSeatTransferPending, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_founder_seat_should_move_founder_record() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.invite_founder(charlie, 4000, false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1500);
+            tribe.fund_tribe().expect("should pass");
+            tribe.transfer_founder_seat(dave).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+
+            //ACT
+            tribe.accept_founder_seat(bob).expect("should pass");
 
             //ASSERT
-            assert!(!prev_enabled);
-            assert!(tribe.enabled);
+            assert_eq!(tribe.get_founder(bob).err(), Some(TribeError::NotAFounder));
+            let founder = tribe.get_founder(dave).expect("dave should be a founder");
+            assert!(founder.required);
+            assert!(founder.is_accepted());
+            assert_eq!(founder.amount_funded(), 1500);
+            let founder_ids: Vec<AccountId> = tribe.get_founder_list().expect("should pass").iter().map(|founder| founder.id).collect();
+            assert_eq!(founder_ids, vec![alice, dave, charlie]);
+            assert_eq!(tribe.founder_count, 3);
+            assert_eq!(tribe.total_funded, 1500);
+            match recorded_events().last() {
+                Some(Event::FounderSeatTransferred(event)) => assert_eq!((event.from, event.to), (bob, dave)),
+                _ => panic!("expected FounderSeatTransferred event"),
+            }
         }
 
-//******************************** get_founder_list  ********************************
+//******************************** initial_founder handover  ********************************
         #[ink::test]
-        fn get_founder_list_should_return_vec() {
+        fn propose_initial_founder_should_fail_when_caller_is_not_the_initial_founder() {
             //ASSIGN
-            let tribe = TribeContract::new(NAME.to_string(), 5000);
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
 
             //ACT
-            let founder_list = tribe.get_founder_list().expect("should pass");
+            match tribe.propose_initial_founder(bob) {
+                Ok(_) => assert!(false, "propose initial founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NotInitialFounder, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_initial_founder_should_fail_for_other_founder() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.invite_founder(charlie, 4000, true).expect("should pass");
+            tribe.propose_initial_founder(bob).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+
+            //ACT
+            match tribe.accept_initial_founder() {
+                Ok(_) => assert!(false, "accept initial founder should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::NoInitialFounderHandover, err, "actual error received {}", err.fmt())
+            }
+        }
+
+        #[ink::test]
+        fn accept_initial_founder_should_move_initial_flag() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            tribe.propose_initial_founder(bob).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+
+            //ACT
+            tribe.accept_initial_founder().expect("should pass");
 
             //ASSERT
-            assert_eq!(founder_list.len(), 1);
+            assert!(!tribe.get_founder(alice).expect("alice should be a founder").initial);
+            assert!(tribe.get_founder(bob).expect("bob should be a founder").initial);
+            assert_eq!(tribe.initial_founder_nominee, None);
+            tribe.invite_founder(charlie, 4000, false).expect("bob should be able to invite");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            assert_eq!(tribe.freeze_roster(), Err(TribeError::MissingRole));
+        }
+
+//******************************** treasury  ********************************
+        fn active_tribe() -> TribeContract {
+            active_tribe_with_config(TribeConfig::default())
+        }
+
+        fn active_tribe_with_config(config: TribeConfig) -> TribeContract {
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, true), (charlie, 2000, false)], config);
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+            for (founder, amount) in [(bob, 4000), (charlie, 2000)] {
+                ink_env::test::set_caller::<ink_env::DefaultEnvironment>(founder);
+                tribe.accept_tribe().expect("should pass");
+                ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(amount);
+                tribe.fund_tribe().expect("should pass");
+            }
+            assert_eq!(tribe.status, TribeStatus::Active);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe
         }
 
-//******************************** get_founder_index  ********************************
         #[ink::test]
-        fn get_founder_index_should_return_initial_founder_index() {
+        fn submit_spend_proposal_should_fail_while_forming() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
+            let dave = AccountId::from([0x3; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
 
             //ACT
-            let tribe = TribeContract::new(NAME.to_string(), 5000);
-            match tribe.get_founder_index(alice) {
-                Ok(index) => {
-                    let founders = tribe.get_founder_list().expect("should get founder list");
-                    let alice_founder = &founders[index];
+            match tribe.submit_spend_proposal(dave, 1000, Hash::from([0x9; 32])) {
+                Ok(_) => assert!(false, "submit spend proposal should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::TribeIsNotActive, err, "actual error received {}", err.fmt())
+            }
+        }
 
-                    //ASSERT
-                    assert_eq!(alice_founder.id, alice);
-                },
-                Err(err) => panic!("founder index not found; {}", err.fmt()),
+        #[ink::test]
+        fn submit_spend_proposal_should_fail_above_treasury_balance() {
+            //ASSIGN
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+
+            //ACT
+            match tribe.submit_spend_proposal(dave, 11001, Hash::from([0x9; 32])) {
+                Ok(_) => assert!(false, "submit spend proposal should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::InsufficientTreasuryFunds, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn get_founder_index_should_not_find_index() {
+        fn get_treasury_balance_should_exclude_rejected_founder_funds() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
-            let bob= AccountId::from([0x1; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false), (charlie, 2000, false)],
+                TribeConfig { activation_policy: ActivationPolicy::MinimumTotalFunded(7000), ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            tribe.reject_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(2000);
+
+            //ACT
+            tribe.fund_tribe().expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Active);
+            assert_eq!(tribe.total_funded, 8000);
+            assert_eq!(tribe.get_treasury_balance(), 7000);
+        }
+
+        #[ink::test]
+        fn get_treasury_balance_should_include_forfeited_funds() {
+            //ASSIGN
+            let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, Vec::new(), eject_config(LaggardFunds::Forfeited));
+            tribe.invite_founder_with_installments(bob, vec![Installment { amount: 1000, due_block: 2 }, Installment { amount: 3000, due_block: 3 }], false).expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.accept_tribe().expect("should pass");
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(1000);
+            tribe.fund_tribe().expect("should pass");
+            advance_blocks(4);
 
             //ACT
-            let tribe = TribeContract::new(NAME.to_string(), 5000);
-            match tribe.get_founder_index(bob) {
-                Ok(_) => assert!(false),
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            tribe.vote_to_eject(bob).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.status, TribeStatus::Active);
+            assert_eq!(tribe.forfeited_funds, 1000);
+            assert_eq!(tribe.get_treasury_balance(), 6000);
+        }
+
+        #[ink::test]
+        fn vote_on_spend_proposal_should_fail_when_vote_already_cast() {
+            //ASSIGN
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_spend_proposal(dave, 3000, Hash::from([0x9; 32])).expect("should pass");
+            tribe.vote_on_spend_proposal(id, true).expect("should pass");
+
+            //ACT
+            match tribe.vote_on_spend_proposal(id, true) {
+                Ok(_) => assert!(false, "vote on spend proposal should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::NotAFounder, err),
+                Err(err) => assert_eq!(TribeError::SpendVoteAlreadyCast, err, "actual error received {}", err.fmt())
             }
         }
 
-//******************************** accept_tribe  ********************************
         #[ink::test]
-        fn accept_tribe_should_fail_when_tribe_is_defunct(){
+        fn vote_on_spend_proposal_should_fail_for_transferred_seat_that_already_voted() {
+            //ASSIGN
+            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let eve = AccountId::from([0x4; 32]);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_spend_proposal(eve, 3000, Hash::from([0x9; 32])).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.vote_on_spend_proposal(id, true).expect("should pass");
+            tribe.transfer_founder_seat(dave).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.accept_founder_seat(bob).expect("should pass");
+
+            //ACT
+            match tribe.vote_on_spend_proposal(id, true) {
+                Ok(_) => assert!(false, "vote on spend proposal should not pass"),
+                //ASSERT
+                Err(err) => assert_eq!(TribeError::SpendVoteAlreadyCast, err, "actual error received {}", err.fmt())
+            }
+            assert_eq!(tribe.get_spend_proposal(id).expect("proposal should exist").votes_for, 1);
+        }
+
+        #[ink::test]
+        fn vote_on_spend_proposal_should_transfer_once_approved() {
+            //ASSIGN
+            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_spend_proposal(dave, 3000, Hash::from([0x9; 32])).expect("should pass");
+            assert_eq!(tribe.vote_on_spend_proposal(id, true), Ok(SpendStatus::Open));
+            set_contract_balance(11000);
+            ink_env::test::set_account_balance::<ink_env::DefaultEnvironment>(dave, 0);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+
+            //ACT
+            let status = tribe.vote_on_spend_proposal(id, true).expect("should pass");
+
+            //ASSERT
+            assert_eq!(status, SpendStatus::Executed);
+            assert_eq!(ink_env::test::get_account_balance::<ink_env::DefaultEnvironment>(dave), Ok(3000));
+            assert_eq!(tribe.get_treasury_balance(), 8000);
+            assert_eq!(tribe.total_funded, 11000);
+            match recorded_events().last() {
+                Some(Event::SpendExecuted(event)) => assert_eq!((event.id, event.recipient, event.amount), (id, dave, 3000)),
+                _ => panic!("expected SpendExecuted event"),
+            }
+        }
+
+        #[ink::test]
+        fn vote_on_spend_proposal_should_reject_without_majority() {
+            //ASSIGN
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_spend_proposal(dave, 3000, Hash::from([0x9; 32])).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.vote_on_spend_proposal(id, false).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
+
+            //ACT
+            let status = tribe.vote_on_spend_proposal(id, false).expect("should pass");
+
+            //ASSERT
+            assert_eq!(status, SpendStatus::Rejected);
+            assert_eq!(tribe.get_treasury_balance(), 11000);
+            assert_eq!(tribe.vote_on_spend_proposal(id, true), Err(TribeError::SpendVoteAlreadyCast));
+        }
+
+//******************************** governance  ********************************
+        #[ink::test]
+        fn submit_proposal_should_fail_for_pending_founder() {
             //ASSIGN
+            let bob = AccountId::from([0x1; 32]);
             let alice = AccountId::from([0x0; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = TribeContract::new_with_config(NAME.to_string(), 5000, DEADLINE, vec![(bob, 4000, false)],
+                TribeConfig { activation_policy: ActivationPolicy::MinimumTotalFunded(5000), ..TribeConfig::default() });
+            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(5000);
+            tribe.fund_tribe().expect("should pass");
+            assert_eq!(tribe.status, TribeStatus::Active);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
 
             //ACT
-            tribe.defunct = true;
-            match tribe.accept_tribe() {
-                Ok(_) => assert!(false, "Should not accept tribe"),
+            match tribe.submit_proposal(Hash::from([0x9; 32])) {
+                Ok(_) => assert!(false, "submit proposal should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::TribeIsDefunct, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::FounderVoteActionPending, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn accept_tribe_should_fail_when_tribe_is_enabled(){
+        fn close_proposal_should_fail_within_voting_period() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            advance_blocks(100);
 
             //ACT
-            tribe.enabled = true;
-            match tribe.accept_tribe() {
-                Ok(_) => assert!(false, "Should not accept tribe"),
+            match tribe.close_proposal(id) {
+                Ok(_) => assert!(false, "close proposal should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::VotingPeriodNotEnded, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn accept_tribe_should_fail_when_caller_is_not_founder() {
+        fn vote_on_proposal_should_fail_when_vote_already_cast() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            tribe.vote_on_proposal(id, true).expect("should pass");
 
             //ACT
-            let bob = AccountId::from([0x1; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            match tribe.accept_tribe() {
-                Ok(_) => assert!(false, "Should not accept tribe"),
+            match tribe.vote_on_proposal(id, false) {
+                Ok(_) => assert!(false, "vote on proposal should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::ProposalVoteAlreadyCast, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn accept_tribe_should_fail_when_caller_already_rejected_tribe() {
+        fn close_proposal_should_weigh_votes_by_shares() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let bob = AccountId::from([0x1; 32]);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            assert_eq!(tribe.vote_on_proposal(id, true), Ok(5000));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(tribe.vote_on_proposal(id, false), Ok(4000));
+            advance_blocks(101);
 
             //ACT
-            match tribe.get_founder_index(alice) {
-                Ok(index) => {
-                    // Mark founder as rejected
-                    let mut founders = tribe.get_founder_list().expect("should pass");
-                    founders[index].vote_action = FOUNDER_REJECTED;
-                    tribe.founders.insert(0, &founders);
-
-                    match tribe.accept_tribe() {
-                        Ok(_) => assert!(false, "Should not accept tribe"),
-                        //ASSERT
-                        Err(err) => assert_eq!(TribeError::FounderRejectedInvitation, err, "actual error received {}", err.fmt())
-                    }
+            let status = tribe.close_proposal(id).expect("should pass");
 
-                },
-                Err(err) => panic!("founder index not found; Error={}", err.fmt())
-            }
+            //ASSERT
+            assert_eq!(status, ProposalStatus::Passed);
+            let proposal = tribe.get_proposal(id).expect("proposal should exist");
+            assert_eq!((proposal.weight_for, proposal.weight_against, proposal.total_weight), (5000, 4000, 11000));
         }
 
         #[ink::test]
-        fn accept_tribe_should_mark_founder_as_accepted() {
+        fn close_proposal_should_count_one_vote_per_founder() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
-            let founders = tribe.get_founder_list().expect("should get founder list");
+            let bob = AccountId::from([0x1; 32]);
+            let governance = GovernanceConfig { weighting: VoteWeighting::OnePerFounder, ..GovernanceConfig::default() };
+            let mut tribe = active_tribe_with_config(TribeConfig { governance, ..TribeConfig::default() });
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            tribe.vote_on_proposal(id, true).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.vote_on_proposal(id, false).expect("should pass");
+            advance_blocks(101);
 
             //ACT
-            tribe.accept_tribe().expect("Should have passed");
+            let status = tribe.close_proposal(id).expect("should pass");
 
             //ASSERT
-            match tribe.get_founder_index(alice) {
-                Ok(index) => {
-                    let founder = &founders[index];
-                    assert_eq!(founder.is_accepted(), false);
-                },
-                Err(err) => panic!("founder index not found; Error={}", err.fmt())
+            assert_eq!(status, ProposalStatus::Failed);
+            assert_eq!(tribe.get_proposal(id).expect("proposal should exist").total_weight, 3);
+            match recorded_events().last() {
+                Some(Event::ProposalClosed(event)) => assert_eq!((event.id, event.status), (id, ProposalStatus::Failed)),
+                _ => panic!("expected ProposalClosed event"),
             }
         }
 
-//******************************** invite_founder  ********************************
         #[ink::test]
-        fn invite_founder_should_fail_when_tribe_is_defunct(){
+        fn vote_on_proposal_should_fail_for_transferred_seat_that_already_voted() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
             let bob = AccountId::from([0x1; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let dave = AccountId::from([0x3; 32]);
+            let governance = GovernanceConfig { weighting: VoteWeighting::OnePerFounder, ..GovernanceConfig::default() };
+            let mut tribe = active_tribe_with_config(TribeConfig { governance, ..TribeConfig::default() });
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.vote_on_proposal(id, true).expect("should pass");
+            tribe.transfer_founder_seat(dave).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.accept_founder_seat(bob).expect("should pass");
 
             //ACT
-            tribe.defunct = true;
-            match tribe.invite_founder(bob, 4000, false) {
-                Ok(_) => assert!(false, "Invite founder should not pass"),
+            match tribe.vote_on_proposal(id, true) {
+                Ok(_) => assert!(false, "vote on proposal should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::TribeIsDefunct, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::ProposalVoteAlreadyCast, err, "actual error received {}", err.fmt())
             }
+            assert_eq!(tribe.get_proposal(id).expect("proposal should exist").weight_for, 1);
         }
 
+//******************************** membership  ********************************
         #[ink::test]
-        fn invite_founder_should_fail_when_tribe_is_enabled(){
+        fn request_membership_should_fail_for_founder() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
             let bob = AccountId::from([0x1; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = active_tribe();
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
 
             //ACT
-            tribe.enabled = true;
-            match tribe.invite_founder(bob, 4000, false) {
-                Ok(_) => assert!(false, "Invite founder should not pass"),
+            match tribe.request_membership() {
+                Ok(_) => assert!(false, "request membership should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::FounderAlreadyInvited, err, "actual error received {}", err.fmt())
             }
-
         }
 
         #[ink::test]
-        fn invite_founder_should_fail_when_caller_is_not_founder() {
+        fn request_membership_should_fail_when_already_requested() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            let bob = AccountId::from([0x1; 32]);
-            let charlie = AccountId::from([0x2; 32]);
-
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.request_membership().expect("should pass");
 
             //ACT
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            match tribe.invite_founder(charlie, 4000, false) {
-                Ok(_) => assert!(false, "Invite founder should not pass"),
+            match tribe.request_membership() {
+                Ok(_) => assert!(false, "request membership should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::MembershipAlreadyRequested, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn invite_founder_should_fail_when_caller_is_not_the_initial_founder() {
+        fn approve_membership_should_admit_on_initial_founder_approval() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
-            let bob = AccountId::from([0x1; 32]);
-            let charlie = AccountId::from([0x2; 32]);
-
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.request_membership().expect("should pass");
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
-            tribe.invite_founder(bob, 4000, false).expect("should pass");
 
             //ACT
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            match tribe.invite_founder(charlie, 4000, false) {
-                Ok(_) => assert!(false, "Invite founder should not pass"),
-                //ASSERT
-                Err(err) => assert_eq!(TribeError::NotInitialFounder, err, "actual error received {}", err.fmt())
-            }
+            let admitted = tribe.approve_membership(dave).expect("should pass");
+
+            //ASSERT
+            assert!(admitted);
+            assert!(tribe.is_member(dave));
+            assert_eq!(tribe.get_member_count(), 1);
+            assert_eq!(tribe.get_member(dave).expect("dave should be a member").joined_block, Some(0));
         }
 
         #[ink::test]
-        fn invite_founder_should_fail_to_invite_the_initial_founder() {
+        fn approve_membership_should_fail_for_transferred_seat_that_already_approved() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let eve = AccountId::from([0x4; 32]);
+            let mut tribe = active_tribe();
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.request_membership().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.approve_membership(dave).expect("should pass");
+            tribe.transfer_founder_seat(eve).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(eve);
+            tribe.accept_founder_seat(bob).expect("should pass");
 
             //ACT
-            match tribe.invite_founder(alice, 4000, false) {
-                Ok(_) => assert!(false, "Invite founder should not pass"),
+            match tribe.approve_membership(dave) {
+                Ok(_) => assert!(false, "approve membership should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::CanNotInviteInitialFounder, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::MembershipVoteAlreadyCast, err, "actual error received {}", err.fmt())
             }
+            assert!(!tribe.is_member(dave));
+            assert_eq!(tribe.get_member(dave).expect("dave should have requested").approvals, 1);
         }
 
         #[ink::test]
-        fn invite_founder_should_fail_to_invite_same_account_more_than_once() {
+        fn approve_membership_should_admit_on_founder_majority() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
             let bob = AccountId::from([0x1; 32]);
-
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
-            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            let charlie = AccountId::from([0x2; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.request_membership().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(tribe.approve_membership(dave), Ok(false));
+            assert!(!tribe.is_member(dave));
+            assert_eq!(tribe.approve_membership(dave), Err(TribeError::MembershipVoteAlreadyCast));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(charlie);
 
             //ACT
-            match tribe.invite_founder(bob, 4000, false) {
-                Ok(_) => assert!(false, "Invite founder should not pass"),
-                //ASSERT
-                Err(err) => assert_eq!(TribeError::FounderAlreadyInvited, err, "actual error received {}", err.fmt())
+            let admitted = tribe.approve_membership(dave).expect("should pass");
+
+            //ASSERT
+            assert!(admitted);
+            assert!(tribe.is_member(dave));
+            match recorded_events().last() {
+                Some(Event::MemberAdded(event)) => assert_eq!(event.member, dave),
+                _ => panic!("expected MemberAdded event"),
             }
         }
 
         #[ink::test]
-        fn invite_founder_should_fail_when_any_founder_has_activity() {
+        fn remove_member_should_fail_for_other_founder() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
             let bob = AccountId::from([0x1; 32]);
-            let charlie = AccountId::from([0x2; 32]);
-
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.request_membership().expect("should pass");
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
-            tribe.invite_founder(bob, 4000, false).expect("should pass");
-
+            tribe.approve_membership(dave).expect("should pass");
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            tribe.reject_tribe().expect("bob should be able to reject tribe");
 
             //ACT
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            match tribe.invite_founder(charlie, 4000, false) {
-                Ok(_) => assert!(false, "Invite founder should not pass"),
+            match tribe.remove_member(dave) {
+                Ok(_) => assert!(false, "remove member should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::TribeIsLocked, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::NotAllowedToRemoveMember, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn invite_founder_should_succeed() {
+        fn remove_member_should_forget_earlier_approvals() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
             let bob = AccountId::from([0x1; 32]);
-
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.request_membership().expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.approve_membership(dave).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
 
             //ACT
-            tribe.invite_founder(bob, 4000, false).expect("should pass");
+            tribe.remove_member(dave).expect("should pass");
 
             //ASSERT
-            let founders = tribe.get_founder_list().expect("should get founder list");
-            assert_eq!(founders.len(), 2);
+            assert_eq!(tribe.get_member(dave), None);
+            tribe.request_membership().expect("should pass");
+            assert_eq!(tribe.get_member(dave).expect("dave should have requested").approvals, 0);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(tribe.approve_membership(dave), Ok(false));
         }
 
-//******************************** fund_tribe  ********************************
+//******************************** roles  ********************************
         #[ink::test]
-        fn fund_tribe_should_fail_when_tribe_is_defunct() {
+        fn grant_role_should_fail_without_admin_role() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.invite_founder(bob, 4000, true).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
 
             //ACT
-            tribe.defunct = true;
-            match tribe.fund_tribe() {
-                Ok(_) => assert!(false, "fund tribe should not pass"),
+            match tribe.grant_role(bob, Role::Inviter) {
+                Ok(_) => assert!(false, "grant role should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(TribeError::TribeIsDefunct, err, "actual error received {}", err.fmt())
+                Err(err) => assert_eq!(TribeError::MissingRole, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn fund_tribe_should_fail_when_tribe_is_enabled(){
+        fn grant_role_should_delegate_inviting() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
 
             //ACT
-            tribe.enabled = true;
-            match tribe.fund_tribe() {
-                Ok(_) => assert!(false, "fund tribe should not pass"),
-                //ASSERT
-                Err(err) => assert_eq!(TribeError::ActiveTribeCannotAcceptFounderAction, err, "actual error received {}", err.fmt())
+            tribe.grant_role(dave, Role::Inviter).expect("should pass");
+
+            //ASSERT
+            assert!(tribe.has_role(dave, Role::Inviter));
+            assert!(!tribe.has_role(dave, Role::Treasurer));
+            assert_eq!(tribe.grant_role(dave, Role::Inviter), Err(TribeError::RoleAlreadyGranted));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            tribe.invite_founder(bob, 4000, true).expect("dave should be able to invite");
+            assert_eq!(tribe.grant_role(dave, Role::Admin), Err(TribeError::MissingRole));
+            match recorded_events().last() {
+                Some(Event::FounderInvited(event)) => assert_eq!(event.founder, bob),
+                _ => panic!("expected FounderInvited event"),
             }
         }
 
         #[ink::test]
-        fn fund_tribe_should_fail_when_caller_is_not_founder(){
+        fn grant_role_should_let_admin_grant_roles() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
-            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let eve = AccountId::from([0x4; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.grant_role(dave, Role::Admin).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
 
             //ACT
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            match tribe.fund_tribe() {
-                Ok(_) => assert!(false, "fund tribe should not pass"),
-                //ASSERT
-                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
+            tribe.grant_role(eve, Role::Treasurer).expect("should pass");
+
+            //ASSERT
+            assert!(tribe.has_role(dave, Role::Inviter));
+            assert!(tribe.has_role(eve, Role::Treasurer));
+            match recorded_events().last() {
+                Some(Event::RoleGranted(event)) => assert_eq!((event.account, event.role, event.admin), (eve, Role::Treasurer, dave)),
+                _ => panic!("expected RoleGranted event"),
             }
         }
 
         #[ink::test]
-        fn fund_tribe_without_value_should_fail(){
+        fn revoke_role_should_fail_for_implied_role() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
 
             //ACT
-            match tribe.fund_tribe() {
-                Ok(_) => assert!(false, "fund tribe should not pass"),
+            match tribe.revoke_role(alice, Role::Admin) {
+                Ok(_) => assert!(false, "revoke role should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(err, TribeError::FundingAmountMustBeGreaterThanZero)
+                Err(err) => assert_eq!(TribeError::RoleNotGranted, err, "actual error received {}", err.fmt())
             }
         }
 
         #[ink::test]
-        fn fund_tribe_should_accept_funds(){
+        fn revoke_role_should_remove_granted_role() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
+            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
-            tribe.accept_tribe().expect("should pass");
+            let mut tribe = TribeContract::new(NAME.to_string(), 5000, DEADLINE);
+            tribe.grant_role(dave, Role::Inviter).expect("should pass");
 
             //ACT
-            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
-            let funding = tribe.fund_tribe().expect("should pass");
+            tribe.revoke_role(dave, Role::Inviter).expect("should pass");
 
             //ASSERT
-            assert_eq!(tribe.enabled, false);
-            assert_eq!(funding, 3000);
+            assert!(!tribe.has_role(dave, Role::Inviter));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
+            assert_eq!(tribe.invite_founder(bob, 4000, true), Err(TribeError::MissingRole));
         }
 
         #[ink::test]
-        fn fund_tribe_should_accept_full_funding(){
+        fn treasurer_should_submit_spend_proposal_without_founder_seat() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
-            tribe.accept_tribe().expect("should pass");
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            tribe.grant_role(dave, Role::Treasurer).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
 
             //ACT
-            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(3000);
-            let funding1 = tribe.fund_tribe().expect("should pass");
-
-            ink_env::test::set_value_transferred::<ink_env::DefaultEnvironment>(2000);
-            let funding2 = tribe.fund_tribe().expect("should pass");
+            let id = tribe.submit_spend_proposal(dave, 1000, Hash::from([0x9; 32])).expect("should pass");
 
             //ASSERT
-            assert!(tribe.enabled);
-            assert_eq!(funding1, 3000);
-            assert_eq!(funding2, 5000);
+            assert_eq!(tribe.get_spend_proposal(id).expect("proposal should exist").proposer, dave);
+            assert_eq!(tribe.vote_on_spend_proposal(id, true), Err(TribeError::NotAFounder));
         }
 
-        //******************************** get_founder_status  ********************************
         #[ink::test]
-        fn get_founder_status_should_return_not_found_message() {
+        fn revoke_role_should_fail_for_initial_founder_roles() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let tribe = TribeContract::new(NAME.to_string(), 5000);
+            let bob = AccountId::from([0x1; 32]);
+            let mut tribe = active_tribe();
+            tribe.grant_role(bob, Role::Admin).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
 
             //ACT
-            let bob = AccountId::from([0x1; 32]);
-            match tribe.get_founder_status(bob) {
-                Ok(_) => assert!(false, "should not pass"),
+            match tribe.revoke_role(alice, Role::Admin) {
+                Ok(_) => assert!(false, "revoke role should not pass"),
                 //ASSERT
-                Err(err) => assert_eq!(err, TribeError::NotAFounder)
+                Err(err) => assert_eq!(TribeError::RoleNotGranted, err, "actual error received {}", err.fmt())
             }
+            assert!(tribe.has_role(alice, Role::Admin));
         }
 
+//******************************** shares  ********************************
         #[ink::test]
-        fn get_founder_status_should_return_founder_description() {
-
+        fn activate_tribe_should_mint_shares_to_founders() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let tribe = TribeContract::new(NAME.to_string(), 5000);
+            let bob = AccountId::from([0x1; 32]);
+            let charlie = AccountId::from([0x2; 32]);
 
             //ACT
-            let status = tribe.get_founder_status(alice).expect("should pass");
-
-            ink_env::debug_println!("received status: {} ", status);
+            let tribe = active_tribe();
 
             //ASSERT
-            assert_eq!(status, r#"{
-    "initial": true,
-    "required": true,
-    "rejected": false,
-    "completed": false,
-    "amount_promised": 5000,
-    "amount_funded": 0
-}"#);
-        }
-
-//******************************** get_tribe  ********************************
-        macro_rules! get_tribe_should_return_expected {
-            ($($name:ident: $value:expr,)*) => {
-            $(
-                #[ink::test]
-                fn $name() {
-                    //ASSIGN
-                    let (name, enabled, defunct, expected) = $value;
-                    let mut tribe = TribeContract::new(name.to_string(), 5000);
-                    tribe.enabled = enabled;
-                    tribe.defunct = defunct;
-
-                    //ACT
-                    let result = tribe.get_tribe();
-
-                    //ASSERT
-                    assert_eq!(expected, result);
-                }
-            )*
-            }
+            assert_eq!(tribe.total_supply(), 11000);
+            assert_eq!(tribe.balance_of(alice), 5000);
+            assert_eq!(tribe.balance_of(bob), 4000);
+            assert_eq!(tribe.balance_of(charlie), 2000);
+            assert_eq!(tribe.token_name(), Some(NAME.to_string()));
+            assert_eq!(tribe.token_decimals(), 12);
         }
 
-        get_tribe_should_return_expected! {
-            get_tribe_not_enabled_not_defunct: ("alice's massive tribe", false, false, "{\n    \"name\": alice's massive tribe,\n    \"enabled\": false,\n    \"defunct\": false\n}"),
-            get_tribe_enabled_not_defunct: ("yet another tribe", true, false, "{\n    \"name\": yet another tribe,\n    \"enabled\": true,\n    \"defunct\": false\n}"),
-            get_tribe_not_enabled_defunct: ("a defunct tribe", false, true, "{\n    \"name\": a defunct tribe,\n    \"enabled\": false,\n    \"defunct\": true\n}"),
-        }
-
-//******************************** reject_tribe  ********************************
-
         #[ink::test]
-        fn reject_tribe_should_fail_when_tribe_is_defunct(){
+        fn transfer_should_fail_with_insufficient_balance() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
 
             //ACT
-            tribe.defunct = true;
-            match tribe.reject_tribe() {
-                Ok(_) => assert!(false, "reject tribe should not pass"),
-                //ASSERT
-                Err(err) => assert_eq!(err, TribeError::TribeIsDefunct)
-            }
+            let result = tribe.transfer(dave, 5001, Vec::new());
+
+            //ASSERT
+            assert_eq!(result, Err(PSP22Error::InsufficientBalance));
         }
 
         #[ink::test]
-        fn reject_tribe_should_fail_when_tribe_is_enabled(){
+        fn transfer_from_should_spend_allowance() {
             //ASSIGN
             let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            tribe.approve(bob, 1500).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(tribe.transfer_from(alice, dave, 2000, Vec::new()), Err(PSP22Error::InsufficientAllowance));
 
             //ACT
-            tribe.enabled = true;
-            match tribe.reject_tribe() {
-                Ok(_) => assert!(false, "reject tribe should not pass"),
-                //ASSERT
-                Err(err) => assert_eq!(err, TribeError::ActiveTribeCannotAcceptFounderAction)
-            }
+            tribe.transfer_from(alice, dave, 1000, Vec::new()).expect("should pass");
+
+            //ASSERT
+            assert_eq!(tribe.balance_of(alice), 4000);
+            assert_eq!(tribe.balance_of(dave), 1000);
+            assert_eq!(tribe.allowance(alice, bob), 500);
+            assert_eq!(tribe.total_supply(), 11000);
         }
 
         #[ink::test]
-        fn reject_tribe_should_fail_when_caller_is_not_founder() {
+        fn transfer_should_fail_while_shares_back_an_open_vote() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let bob = AccountId::from([0x1; 32]);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            tribe.vote_on_proposal(id, true).expect("should pass");
 
             //ACT
-            let bob = AccountId::from([0x1; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            match tribe.reject_tribe() {
-                Ok(_) => assert!(false, "reject tribe should not pass"),
-                //ASSERT
-                Err(err) => assert_eq!(TribeError::NotAFounder, err, "actual error received {}", err.fmt())
-            }
+            let result = tribe.transfer(bob, 1000, Vec::new());
+
+            //ASSERT
+            assert_eq!(result, Err(PSP22Error::shares_locked()));
+            advance_blocks(101);
+            tribe.transfer(bob, 1000, Vec::new()).expect("shares should be unlocked once the vote ended");
+            assert_eq!(tribe.balance_of(bob), 5000);
         }
 
         #[ink::test]
-        fn reject_tribe_should_succeed_and_mark_tribe_as_defunct() {
+        fn accept_founder_seat_should_fail_while_shares_back_an_open_vote() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
-            let prev_defunct = tribe.defunct;
+            let bob = AccountId::from([0x1; 32]);
+            let dave = AccountId::from([0x3; 32]);
+            let mut tribe = active_tribe();
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            tribe.transfer_founder_seat(dave).expect("should pass");
+            tribe.vote_on_proposal(id, true).expect("should pass");
+            assert_eq!(tribe.transfer_founder_seat(dave), Err(TribeError::SeatSharesLocked));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(dave);
 
             //ACT
-            tribe.reject_tribe().expect("should pass");
+            let result = tribe.accept_founder_seat(bob);
 
             //ASSERT
-            assert_eq!(prev_defunct, false);
-
-            assert!(tribe.defunct);
+            assert_eq!(result, Err(TribeError::SeatSharesLocked));
+            assert_eq!(tribe.vote_on_proposal(id, true), Err(TribeError::NotAFounder));
+            assert_eq!(tribe.get_proposal(id).expect("proposal should exist").weight_for, 4000);
+            assert_eq!(tribe.balance_of(dave), 0);
+            advance_blocks(101);
+            tribe.accept_founder_seat(bob).expect("seat should be transferable once the vote ended");
+            assert_eq!(tribe.balance_of(dave), 4000);
         }
 
         #[ink::test]
-        fn reject_tribe_should_succeed_and_mark_founder_vote() {
+        fn vote_on_proposal_should_weigh_transferred_shares() {
             //ASSIGN
-            let alice = AccountId::from([0x0; 32]);
-            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let mut tribe = TribeContract::new(NAME.to_string(), 5000);
+            let bob = AccountId::from([0x1; 32]);
+            let mut tribe = active_tribe();
+            tribe.transfer(bob, 3000, Vec::new()).expect("should pass");
+            let id = tribe.submit_proposal(Hash::from([0x9; 32])).expect("should pass");
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
 
             //ACT
-            tribe.reject_tribe().expect("should pass");
+            let weight = tribe.vote_on_proposal(id, true).expect("should pass");
 
             //ASSERT
-            let founders = tribe.get_founder_list().expect("should get list");
-            match tribe.get_founder_index(alice) {
-                Ok(index) => {
-                    let founder = &founders[index];
-                    assert!(founder.is_rejected());
-                },
-                Err(err) => panic!("founder index not found; Error={}", err.fmt()),
-            }
+            assert_eq!(weight, 7000);
+            assert_eq!(tribe.get_proposal(id).expect("proposal should exist").total_weight, 11000);
         }
 
     }
 }
-    
\ No newline at end of file
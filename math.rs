@@ -0,0 +1,86 @@
+/// Computes `a * b / divisor` rounded down without overflowing on the intermediate product.
+/// Returns `None` when `divisor` is 0 or the result does not fit in a `u128`
+pub fn mul_div(a: u128, b: u128, divisor: u128) -> Option<u128> {
+    if divisor == 0 {
+        return None;
+    }
+    if let Some(product) = a.checked_mul(b) {
+        return Some(product / divisor);
+    }
+
+    let (high, low) = full_mul(a, b);
+    if high >= divisor {
+        return None;
+    }
+
+    // long division of the 256 bit product, one bit of `low` at a time. `remainder` stays below `divisor`,
+    // so when shifting it carries out of 128 bits the true remainder is above `divisor` and the wrapping subtraction is exact
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+
+    Some(quotient)
+}
+
+/// Multiplies `a` by `b` into a 256 bit `(high, low)` pair, built from 64 bit limbs
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const LIMB: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & LIMB);
+    let (b_high, b_low) = (b >> 64, b & LIMB);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (low_high & LIMB) + (high_low & LIMB);
+    let low = (middle << 64) | (low_low & LIMB);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+///
+/// Math Unit Tests
+///
+#[cfg(test)]
+mod math_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    //a, b, divisor, expected
+    macro_rules! mul_div_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (a, b, divisor, expected): (u128, u128, u128, Option<u128>) = $value;
+
+                //ACT
+                let result = mul_div(a, b, divisor);
+
+                //ASSERT
+                assert_eq!(result, expected);
+            }
+        )*
+        }
+    }
+    mul_div_tests! {
+        mul_div_small_values: (6000, 10000, 15000, Some(4000)),
+        mul_div_rounds_down: (10, 10, 3, Some(33)),
+        mul_div_by_zero: (10, 10, 0, None),
+        mul_div_wide_product: (u128::MAX, u128::MAX - 1, u128::MAX, Some(u128::MAX - 1)),
+        mul_div_wide_product_rounds_down: (u128::MAX, u128::MAX - 2, u128::MAX - 1, Some(u128::MAX - 2)),
+        mul_div_wide_product_halved: (u128::MAX, 2, 4, Some(u128::MAX / 2)),
+        mul_div_wide_powers_of_two: (1 << 100, 1 << 100, 1 << 90, Some(1 << 110)),
+        mul_div_result_too_large: (u128::MAX, 3, 2, None),
+    }
+}
@@ -0,0 +1,105 @@
+use ink_env::AccountId;
+use ink_storage::traits::{SpreadLayout, PackedLayout};
+use crate::errors::TribeError;
+
+/// Where an account stands in joining an active tribe
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum MemberStatus {
+    Requested,
+    Approved
+}
+
+/// A regular member of an active tribe, or an account waiting to be approved as one
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct Member {
+    pub id: AccountId,
+    /// Identifies this request so approvals cast on an earlier request of the same account are not counted
+    pub request_id: u32,
+    pub status: MemberStatus,
+    pub requested_block: u32,
+    pub joined_block: Option<u32>,
+    /// Founders who voted to approve this request
+    pub approvals: u32,
+}
+
+impl Member {
+
+    pub fn new(id: AccountId, request_id: u32, requested_block: u32) -> Self {
+        Self {
+            id,
+            request_id,
+            status: MemberStatus::Requested,
+            requested_block,
+            joined_block: None,
+            approvals: 0,
+        }
+    }
+
+    /// Counts one founder's approval, returns the approvals so far
+    pub fn add_approval(&mut self) -> Result<u32, TribeError> {
+        if self.is_approved() {
+            return Err(TribeError::MemberAlreadyApproved);
+        }
+
+        self.approvals += 1;
+        Ok(self.approvals)
+    }
+
+    pub fn approve(&mut self, block_number: u32) -> Result<(), TribeError> {
+        if self.is_approved() {
+            return Err(TribeError::MemberAlreadyApproved);
+        }
+
+        self.status = MemberStatus::Approved;
+        self.joined_block = Some(block_number);
+        Ok(())
+    }
+
+    pub fn is_approved(&self) -> bool {
+        self.status == MemberStatus::Approved
+    }
+}
+
+///
+/// Member Unit Tests
+///
+#[cfg(test)]
+mod member_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    #[ink::test]
+    fn new_member_should_wait_for_approval() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+
+        //ACT
+        let member = Member::new(alice, 3, 12);
+
+        //ASSERT
+        assert_eq!(member.status, MemberStatus::Requested);
+        assert_eq!(member.request_id, 3);
+        assert_eq!(member.requested_block, 12);
+        assert_eq!(member.joined_block, None);
+        assert!(!member.is_approved());
+    }
+
+    #[ink::test]
+    fn approve_should_record_joined_block() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+        let mut member = Member::new(alice, 0, 12);
+        assert_eq!(member.add_approval(), Ok(1));
+
+        //ACT
+        member.approve(15).expect("approve should be ok");
+
+        //ASSERT
+        assert!(member.is_approved());
+        assert_eq!(member.joined_block, Some(15));
+        assert_eq!(member.add_approval(), Err(TribeError::MemberAlreadyApproved));
+        assert_eq!(member.approve(16), Err(TribeError::MemberAlreadyApproved));
+    }
+}
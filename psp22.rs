@@ -0,0 +1,22 @@
+use ink_prelude::string::String;
+
+/// Errors defined by the PSP22 standard, returned by the share token messages
+#[derive(scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum PSP22Error {
+    /// Any other error, described by the message
+    Custom(String),
+    InsufficientBalance,
+    InsufficientAllowance,
+    ZeroRecipientAddress,
+    ZeroSenderAddress,
+    SafeTransferCheckFailed(String),
+}
+
+impl PSP22Error {
+
+    /// Returned when shares are moved out of an account that voted on a governance proposal which is still open
+    pub fn shares_locked() -> Self {
+        PSP22Error::Custom(String::from("Shares are locked until the proposals voted on have ended"))
+    }
+}
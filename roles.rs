@@ -0,0 +1,61 @@
+use ink_storage::traits::{SpreadLayout, PackedLayout};
+
+/// Permissions that can be delegated without handing over the initial founder seat.
+/// The initial founder implicitly holds every role for as long as they hold the seat, those roles can not be revoked
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum Role {
+    /// Grants and revokes roles, admits and removes members, and implies every other role
+    Admin,
+    /// Submits spend proposals
+    Treasurer,
+    /// Manages the founder roster while the tribe is forming
+    Inviter
+}
+
+impl Role {
+
+    pub const ALL: [Role; 3] = [Role::Admin, Role::Treasurer, Role::Inviter];
+
+    /// Whether holding this role is enough to act as `role`
+    pub fn grants(&self, role: Role) -> bool {
+        *self == Role::Admin || *self == role
+    }
+}
+
+///
+/// Roles Unit Tests
+///
+#[cfg(test)]
+mod roles_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    //held, required, expected
+    macro_rules! role_grants {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (held, required, expected): (Role, Role, bool) = $value;
+
+                //ACT
+                let granted = held.grants(required);
+
+                //ASSERT
+                assert_eq!(granted, expected);
+            }
+        )*
+        }
+    }
+    role_grants! {
+        grants_admin_admin: (Role::Admin, Role::Admin, true),
+        grants_admin_treasurer: (Role::Admin, Role::Treasurer, true),
+        grants_admin_inviter: (Role::Admin, Role::Inviter, true),
+        grants_treasurer_treasurer: (Role::Treasurer, Role::Treasurer, true),
+        grants_treasurer_inviter: (Role::Treasurer, Role::Inviter, false),
+        grants_inviter_admin: (Role::Inviter, Role::Admin, false),
+        grants_inviter_treasurer: (Role::Inviter, Role::Treasurer, false),
+    }
+}
@@ -0,0 +1,157 @@
+use ink_primitives::KeyPtr;
+use ink_storage::traits::{SpreadAllocate, SpreadLayout, PackedLayout};
+use crate::errors::TribeError;
+
+/// A founder's answer to their invitation
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum VoteAction {
+    Pending,
+    Accepted,
+    Rejected
+}
+
+/// Lifecycle of a tribe. `Forming` is the only status that accepts founder actions, `Active` the only one that accepts treasury actions
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum TribeStatus {
+    Forming,
+    Active,
+    Defunct,
+    Dissolved
+}
+
+impl SpreadAllocate for TribeStatus {
+    fn allocate_spread(ptr: &mut KeyPtr) -> Self {
+        ptr.advance_by(<Self as SpreadLayout>::FOOTPRINT);
+        TribeStatus::Forming
+    }
+}
+
+impl TribeStatus {
+
+    /// Moves to `next`, only `Forming -> Active`, `Forming -> Defunct` and `Defunct -> Dissolved` are legal
+    pub fn transition(&mut self, next: TribeStatus) -> Result<(), TribeError> {
+        match (*self, next) {
+            (TribeStatus::Forming, TribeStatus::Active)
+            | (TribeStatus::Forming, TribeStatus::Defunct)
+            | (TribeStatus::Defunct, TribeStatus::Dissolved) => {
+                *self = next;
+                Ok(())
+            },
+            _ => Err(TribeError::InvalidStatusTransition)
+        }
+    }
+
+    /// Fails with the error describing why a tribe that is not `Forming` cannot accept founder actions
+    pub fn ensure_forming(&self) -> Result<(), TribeError> {
+        match self {
+            TribeStatus::Forming => Ok(()),
+            TribeStatus::Active => Err(TribeError::ActiveTribeCannotAcceptFounderAction),
+            TribeStatus::Defunct => Err(TribeError::TribeIsDefunct),
+            TribeStatus::Dissolved => Err(TribeError::TribeIsDissolved)
+        }
+    }
+
+    /// Fails with the error describing why a tribe that is not `Active` cannot accept treasury actions
+    pub fn ensure_active(&self) -> Result<(), TribeError> {
+        match self {
+            TribeStatus::Forming => Err(TribeError::TribeIsNotActive),
+            TribeStatus::Active => Ok(()),
+            TribeStatus::Defunct => Err(TribeError::TribeIsDefunct),
+            TribeStatus::Dissolved => Err(TribeError::TribeIsDissolved)
+        }
+    }
+}
+
+///
+/// Status Unit Tests
+///
+#[cfg(test)]
+mod status_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    //from, to, expected
+    macro_rules! tribe_status_transition {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (from, to, expected): (TribeStatus, TribeStatus, Result<(), TribeError>) = $value;
+                let mut status = from;
+
+                //ACT
+                let result = status.transition(to);
+
+                //ASSERT
+                assert_eq!(expected, result);
+                if result.is_ok() {
+                    assert_eq!(status, to);
+                } else {
+                    assert_eq!(status, from);
+                }
+            }
+        )*
+        }
+    }
+    tribe_status_transition! {
+        forming_to_active: (TribeStatus::Forming, TribeStatus::Active, Ok(())),
+        forming_to_defunct: (TribeStatus::Forming, TribeStatus::Defunct, Ok(())),
+        forming_to_dissolved: (TribeStatus::Forming, TribeStatus::Dissolved, Err(TribeError::InvalidStatusTransition)),
+        active_to_forming: (TribeStatus::Active, TribeStatus::Forming, Err(TribeError::InvalidStatusTransition)),
+        active_to_defunct: (TribeStatus::Active, TribeStatus::Defunct, Err(TribeError::InvalidStatusTransition)),
+        defunct_to_active: (TribeStatus::Defunct, TribeStatus::Active, Err(TribeError::InvalidStatusTransition)),
+        defunct_to_dissolved: (TribeStatus::Defunct, TribeStatus::Dissolved, Ok(())),
+        dissolved_to_forming: (TribeStatus::Dissolved, TribeStatus::Forming, Err(TribeError::InvalidStatusTransition)),
+    }
+
+    macro_rules! tribe_status_ensure_forming {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (status, expected): (TribeStatus, Result<(), TribeError>) = $value;
+
+                //ACT
+                let result = status.ensure_forming();
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    tribe_status_ensure_forming! {
+        ensure_forming_forming: (TribeStatus::Forming, Ok(())),
+        ensure_forming_active: (TribeStatus::Active, Err(TribeError::ActiveTribeCannotAcceptFounderAction)),
+        ensure_forming_defunct: (TribeStatus::Defunct, Err(TribeError::TribeIsDefunct)),
+        ensure_forming_dissolved: (TribeStatus::Dissolved, Err(TribeError::TribeIsDissolved)),
+    }
+
+    macro_rules! tribe_status_ensure_active {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (status, expected): (TribeStatus, Result<(), TribeError>) = $value;
+
+                //ACT
+                let result = status.ensure_active();
+
+                //ASSERT
+                assert_eq!(expected, result);
+            }
+        )*
+        }
+    }
+    tribe_status_ensure_active! {
+        ensure_active_forming: (TribeStatus::Forming, Err(TribeError::TribeIsNotActive)),
+        ensure_active_active: (TribeStatus::Active, Ok(())),
+        ensure_active_defunct: (TribeStatus::Defunct, Err(TribeError::TribeIsDefunct)),
+        ensure_active_dissolved: (TribeStatus::Dissolved, Err(TribeError::TribeIsDissolved)),
+    }
+}
@@ -0,0 +1,162 @@
+use ink_env::{AccountId, Hash};
+use ink_storage::traits::{SpreadLayout, PackedLayout};
+use crate::errors::TribeError;
+
+/// Where a spend proposal stands in its vote
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum SpendStatus {
+    Open,
+    Executed,
+    Rejected
+}
+
+/// A founder's request to pay `amount` of the tribe's funds to `recipient`
+#[derive(PackedLayout, SpreadLayout, scale::Encode, scale::Decode, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub struct SpendProposal {
+    pub id: u32,
+    pub proposer: AccountId,
+    pub recipient: AccountId,
+    pub amount: u128,
+    /// Hash of the description agreed off chain
+    pub description_hash: Hash,
+    pub votes_for: u32,
+    pub votes_against: u32,
+    /// Founders able to vote when the proposal was submitted
+    pub voter_count: u32,
+    pub status: SpendStatus,
+}
+
+impl SpendProposal {
+
+    pub fn new(id: u32, proposer: AccountId, recipient: AccountId, amount: u128, description_hash: Hash, voter_count: u32) -> Result<Self, TribeError> {
+        if amount == 0 {
+            return Err(TribeError::SpendAmountIsZero);
+        }
+
+        Ok(Self {
+            id,
+            proposer,
+            recipient,
+            amount,
+            description_hash,
+            votes_for: 0,
+            votes_against: 0,
+            voter_count,
+            status: SpendStatus::Open,
+        })
+    }
+
+    /// Counts one vote. The proposal is approved once more than half of `voter_count` vote for it
+    /// and rejected once half or more vote against it, `is_approved` tells whether it should now be executed
+    pub fn vote(&mut self, approve: bool) -> Result<(), TribeError> {
+        if self.status != SpendStatus::Open {
+            return Err(TribeError::SpendProposalNotOpen);
+        }
+
+        if approve {
+            self.votes_for += 1;
+        } else {
+            self.votes_against += 1;
+            if self.votes_against * 2 >= self.voter_count {
+                self.status = SpendStatus::Rejected;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn is_approved(&self) -> bool {
+        self.status == SpendStatus::Open && self.votes_for * 2 > self.voter_count
+    }
+
+    pub fn execute(&mut self) {
+        self.status = SpendStatus::Executed;
+    }
+}
+
+///
+/// Treasury Unit Tests
+///
+#[cfg(test)]
+mod treasury_tests {
+    use super::*;
+    use ink_lang as ink;
+
+    fn proposal(voter_count: u32) -> SpendProposal {
+        let alice = AccountId::from([0x0; 32]);
+        let bob = AccountId::from([0x1; 32]);
+        SpendProposal::new(0, alice, bob, 1000, Hash::from([0x9; 32]), voter_count).expect("expected proposal")
+    }
+
+    #[ink::test]
+    fn new_should_fail_when_amount_is_zero() {
+        //ASSIGN
+        let alice = AccountId::from([0x0; 32]);
+
+        //ACT
+        let result = SpendProposal::new(0, alice, alice, 0, Hash::from([0x9; 32]), 3);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::SpendAmountIsZero));
+    }
+
+    //voter_count, votes (approve), approved, status
+    macro_rules! spend_proposal_vote {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[ink::test]
+            fn $name() {
+                //ASSIGN
+                let (voter_count, votes, approved, status): (u32, &[bool], bool, SpendStatus) = $value;
+                let mut proposal = proposal(voter_count);
+
+                //ACT
+                for approve in votes {
+                    proposal.vote(*approve).expect("vote should be ok");
+                }
+
+                //ASSERT
+                assert_eq!(proposal.is_approved(), approved);
+                assert_eq!(proposal.status, status);
+            }
+        )*
+        }
+    }
+    spend_proposal_vote! {
+        vote_single_founder_approves: (1, &[true], true, SpendStatus::Open),
+        vote_half_is_not_a_majority: (4, &[true, true], false, SpendStatus::Open),
+        vote_majority_approves: (4, &[true, true, true], true, SpendStatus::Open),
+        vote_half_against_rejects: (4, &[false, true, false], false, SpendStatus::Rejected),
+        vote_minority_against_stays_open: (3, &[false, true], false, SpendStatus::Open),
+    }
+
+    #[ink::test]
+    fn vote_should_fail_once_rejected() {
+        //ASSIGN
+        let mut proposal = proposal(1);
+        proposal.vote(false).expect("vote should be ok");
+
+        //ACT
+        let result = proposal.vote(true);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::SpendProposalNotOpen));
+    }
+
+    #[ink::test]
+    fn vote_should_fail_once_executed() {
+        //ASSIGN
+        let mut proposal = proposal(1);
+        proposal.vote(true).expect("vote should be ok");
+        proposal.execute();
+
+        //ACT
+        let result = proposal.vote(true);
+
+        //ASSERT
+        assert_eq!(result, Err(TribeError::SpendProposalNotOpen));
+        assert!(!proposal.is_approved());
+    }
+}